@@ -0,0 +1,41 @@
+#import bevy_sprite::mesh2d_vertex_output  MeshVertexOutput
+
+struct GridMaterial {
+    background_color: vec4<f32>,
+    small_line_color: vec4<f32>,
+    large_line_color: vec4<f32>,
+    small_box_length: f32,
+    large_box_length: f32,
+    small_line_width: f32,
+    large_line_width: f32,
+};
+
+@group(1) @binding(0)
+var<uniform> material: GridMaterial;
+
+// Distance from `position` to the closest grid line spaced `spacing` apart.
+fn line_distance(position: vec2<f32>, spacing: f32) -> f32 {
+    let offset = abs(position - round(position / spacing) * spacing);
+    return min(offset.x, offset.y);
+}
+
+fn blend(base: vec4<f32>, over: vec4<f32>) -> vec4<f32> {
+    return vec4<f32>(mix(base.rgb, over.rgb, over.a), max(base.a, over.a));
+}
+
+@fragment
+fn fragment(
+    mesh: MeshVertexOutput,
+) -> @location(0) vec4<f32> {
+    let position = mesh.world_position.xy;
+    var output_color = material.background_color;
+
+    if line_distance(position, material.small_box_length) <= material.small_line_width / 2.0 {
+        output_color = blend(output_color, material.small_line_color);
+    }
+    if line_distance(position, material.large_box_length) <= material.large_line_width / 2.0 {
+        output_color = blend(output_color, material.large_line_color);
+    }
+
+    return output_color;
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::Component;
+
+use crate::game::player::characters::Character;
+
+#[derive(Component)]
+pub struct CharacterSelect;
+
+#[derive(Component)]
+pub enum CharacterSelectButton {
+    Character { character: Character, unlocked: bool },
+    Back,
+}
+
+/// Says what unlocks a locked character.
+#[derive(Component)]
+pub struct CharacterSelectStatusText;
@@ -0,0 +1,24 @@
+mod components;
+mod styles;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::systems::{button_interaction, despawn_character_select, spawn_character_select};
+
+/// Shows the character select screen between the main menu and the game. Picking a
+/// character sets `SelectedCharacter` and starts the run.
+pub struct CharacterSelectPlugin;
+
+impl Plugin for CharacterSelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::CharacterSelect), spawn_character_select)
+            .add_systems(
+                Update,
+                button_interaction.run_if(in_state(AppState::CharacterSelect)),
+            )
+            .add_systems(OnExit(AppState::CharacterSelect), despawn_character_select);
+    }
+}
@@ -0,0 +1,202 @@
+use bevy::prelude::*;
+
+use crate::{
+    focus::components::{FocusBack, Focusable},
+    game::{
+        achievements::{
+            definitions::{Achievements, Unlock},
+            resources::AchievementsHandle,
+        },
+        player::{
+            characters::{Character, Characters},
+            resources::{CharactersHandle, SelectedCharacter},
+        },
+    },
+    save::resources::SaveData,
+    states::AppState,
+};
+
+use super::{
+    components::{CharacterSelect, CharacterSelectButton, CharacterSelectStatusText},
+    styles::{
+        get_button_text, get_description_text, get_status_text, get_title_text, BACK_BUTTON_STYLE,
+        CHARACTERS_ROW_STYLE, CHARACTER_BUTTON_STYLE, CHARACTER_SELECT_STYLE, HOVERED_BUTTON_COLOR,
+        LOCKED_BUTTON_COLOR, LOCKED_HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR,
+    },
+};
+
+pub fn spawn_character_select(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    characters_handle: Res<CharactersHandle>,
+    characters: Res<Assets<Characters>>,
+    save_data: Res<SaveData>,
+) {
+    let characters = match characters.get(&characters_handle.0) {
+        Some(characters) => characters.characters.clone(),
+        None => vec![Character::default()],
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: CHARACTER_SELECT_STYLE,
+                ..default()
+            },
+            CharacterSelect {},
+            Name::from("Character Select"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: get_title_text(&asset_server, "Choose a Character"),
+                ..default()
+            });
+
+            parent
+                .spawn(NodeBundle {
+                    style: CHARACTERS_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for character in characters {
+                        spawn_character_button(parent, &asset_server, character, &save_data);
+                    }
+                });
+
+            parent.spawn((
+                TextBundle {
+                    text: get_status_text(&asset_server, ""),
+                    ..default()
+                },
+                CharacterSelectStatusText {},
+            ));
+
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: BACK_BUTTON_STYLE,
+                        ..default()
+                    },
+                    CharacterSelectButton::Back,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                    FocusBack,
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_button_text(&asset_server, "Back"),
+                        ..default()
+                    });
+                });
+        });
+}
+
+/// A card showing the character's name, description and starting weapon. Locked characters
+/// are dimmed and hide their description.
+fn spawn_character_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    character: Character,
+    save_data: &SaveData,
+) {
+    let unlocked = character.is_unlocked(save_data);
+    let (normal, hovered) = button_colors(unlocked);
+    let (description, weapon) = if unlocked {
+        (character.description.clone(), format!("Starts with {}", character.weapon))
+    } else {
+        ("Locked".to_string(), String::new())
+    };
+    let name = character.name.clone();
+
+    parent
+        .spawn((
+            ButtonBundle {
+                style: CHARACTER_BUTTON_STYLE,
+                background_color: normal.into(),
+                ..default()
+            },
+            CharacterSelectButton::Character { character, unlocked },
+            Focusable::new(normal, hovered),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: get_button_text(asset_server, &name),
+                ..default()
+            });
+            parent.spawn(TextBundle {
+                text: get_description_text(asset_server, &description),
+                ..default()
+            });
+            parent.spawn(TextBundle {
+                text: get_description_text(asset_server, &weapon),
+                ..default()
+            });
+        });
+}
+
+fn button_colors(unlocked: bool) -> (Color, Color) {
+    if unlocked {
+        (NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR)
+    } else {
+        (LOCKED_BUTTON_COLOR, LOCKED_HOVERED_BUTTON_COLOR)
+    }
+}
+
+pub fn despawn_character_select(
+    mut commands: Commands,
+    character_select_query: Query<Entity, With<CharacterSelect>>,
+) {
+    if let Ok(character_select_entity) = character_select_query.get_single() {
+        commands.entity(character_select_entity).despawn_recursive();
+    }
+}
+
+/// Starts a run as the pressed character, or says what unlocks it if it's locked.
+pub fn button_interaction(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &CharacterSelectButton),
+        Changed<Interaction>,
+    >,
+    mut status_text_query: Query<&mut Text, With<CharacterSelectStatusText>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut selected_character: ResMut<SelectedCharacter>,
+    achievements_handle: Res<AchievementsHandle>,
+    achievements: Res<Assets<Achievements>>,
+) {
+    for (interaction, mut background_color, character_select_button) in button_query.iter_mut() {
+        let unlocked = match character_select_button {
+            CharacterSelectButton::Character { unlocked, .. } => *unlocked,
+            CharacterSelectButton::Back => true,
+        };
+        let (normal, hovered) = button_colors(unlocked);
+
+        match (*interaction, character_select_button) {
+            (Interaction::Pressed, CharacterSelectButton::Character { character, unlocked: true }) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                selected_character.0 = character.clone();
+                next_app_state.set(AppState::Game);
+            }
+            (Interaction::Pressed, CharacterSelectButton::Character { character, unlocked: false }) => {
+                let unlock = Unlock::Character(character.id.clone());
+                let status = match achievements
+                    .get(&achievements_handle.0)
+                    .and_then(|achievements| achievements.unlocking(&unlock))
+                {
+                    Some(achievement) => format!(
+                        "{} is unlocked by {}: {}",
+                        character.name, achievement.name, achievement.description
+                    ),
+                    None => format!("{} is locked", character.name),
+                };
+                if let Ok(mut status_text) = status_text_query.get_single_mut() {
+                    status_text.sections[0].value = status;
+                }
+            }
+            (Interaction::Pressed, CharacterSelectButton::Back) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::MainMenu);
+            }
+            (Interaction::Hovered, _) => *background_color = hovered.into(),
+            _ => *background_color = normal.into(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use bevy::log::warn;
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// The game's folder within the platform's config and data directories.
+pub const CONFIG_DIR_NAME: &str = "geometry-survivor";
+
+/// Where a config file lives, or `None` if the platform has no config directory.
+pub fn config_path(file_name: &str) -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(file_name))
+}
+
+/// Where a save file lives, or `None` if the platform has no data directory.
+pub fn data_path(file_name: &str) -> Option<PathBuf> {
+    data_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(file_name))
+}
+
+#[cfg(not(test))]
+fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir()
+}
+
+#[cfg(not(test))]
+fn data_dir() -> Option<PathBuf> {
+    dirs::data_dir()
+}
+
+/// Tests get their own directories, so they never read or overwrite the player's files.
+#[cfg(test)]
+fn config_dir() -> Option<PathBuf> {
+    Some(test_dir().join("config"))
+}
+
+#[cfg(test)]
+fn data_dir() -> Option<PathBuf> {
+    Some(test_dir().join("data"))
+}
+
+#[cfg(test)]
+fn test_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("{}-test-{}", CONFIG_DIR_NAME, std::process::id()))
+}
+
+/// Reads a RON config file. Gives `None` if it's missing or invalid, so callers can fall back to
+/// their defaults.
+pub fn load<T: DeserializeOwned>(file_name: &str) -> Option<T> {
+    let path = config_path(file_name)?;
+    let contents = fs::read_to_string(&path).ok()?;
+    match ron::from_str(&contents) {
+        Ok(value) => Some(value),
+        Err(error) => {
+            warn!("Ignoring invalid config file {}: {}", path.display(), error);
+            None
+        }
+    }
+}
+
+/// Writes a RON config file, creating the config directory if needed.
+pub fn save<T: Serialize>(file_name: &str, value: &T) -> io::Result<()> {
+    let path = config_path(file_name)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "no config directory"))?;
+    let contents = ron::ser::to_string_pretty(value, PrettyConfig::default())
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    write_atomic(&path, &contents)
+}
+
+/// Writes a file by way of a temporary one beside it, renamed into place once it's complete,
+/// so a crash mid-write leaves the old file rather than half of the new one. Creates the
+/// file's directory if needed.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    let temp_path = PathBuf::from(temp_path);
+
+    let mut file = File::create(&temp_path)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    fs::rename(&temp_path, path)
+}
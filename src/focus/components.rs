@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+
+/// A button that can be reached without the mouse. It's drawn in `focused` while it has focus,
+/// using the owning menu's own colors.
+#[derive(Component)]
+pub struct Focusable {
+    pub normal: Color,
+    pub focused: Color,
+}
+
+impl Focusable {
+    pub fn new(normal: Color, focused: Color) -> Self {
+        Focusable { normal, focused }
+    }
+}
+
+/// The focusable button that confirming presses. At most one has it at a time.
+#[derive(Component)]
+pub struct Focused;
+
+/// The button a menu presses when backing out of it, like Resume or Back.
+#[derive(Component)]
+pub struct FocusBack;
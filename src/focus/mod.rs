@@ -0,0 +1,35 @@
+pub mod components;
+mod systems;
+
+use bevy::{prelude::*, ui::UiSystem};
+
+use self::systems::{activate_focus, highlight_focus, navigate_focus};
+
+/// How far the left stick has to be pushed, past the deadzone, to move focus.
+pub const FOCUS_STICK_THRESHOLD: f32 = 0.5;
+pub const FOCUS_CONFIRM_BUTTON: GamepadButtonType = GamepadButtonType::South;
+pub const FOCUS_BACK_BUTTON: GamepadButtonType = GamepadButtonType::East;
+pub const FOCUS_PREVIOUS_KEYS: [KeyCode; 3] = [KeyCode::Up, KeyCode::Left, KeyCode::W];
+pub const FOCUS_NEXT_KEYS: [KeyCode; 3] = [KeyCode::Down, KeyCode::Right, KeyCode::S];
+pub const FOCUS_CONFIRM_KEYS: [KeyCode; 3] = [KeyCode::Return, KeyCode::NumpadEnter, KeyCode::Space];
+pub const FOCUS_BACK_KEY: KeyCode = KeyCode::Escape;
+
+/// Lets every menu's `Focusable` buttons be moved between and pressed without the mouse,
+/// from the keyboard or a gamepad, and lets menus with a `FocusBack` button be backed out of.
+///
+/// Focus moves and presses happen right after Bevy's own mouse interactions are worked out,
+/// so menus see them in the same frame as clicks. Menus that need a key for themselves can
+/// consume it before then. Highlighting runs after the menus have recolored their buttons.
+pub struct FocusPlugin;
+
+impl Plugin for FocusPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PreUpdate,
+            (navigate_focus, apply_deferred, activate_focus)
+                .chain()
+                .after(UiSystem::Focus),
+        )
+        .add_systems(PostUpdate, highlight_focus);
+    }
+}
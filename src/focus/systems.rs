@@ -0,0 +1,239 @@
+use std::cmp::Ordering;
+
+use bevy::{ecs::query::Has, prelude::*};
+
+use crate::input::gamepad::{any_just_pressed, left_stick};
+
+use super::{
+    components::{FocusBack, Focusable, Focused},
+    FOCUS_BACK_BUTTON, FOCUS_BACK_KEY, FOCUS_CONFIRM_BUTTON, FOCUS_CONFIRM_KEYS, FOCUS_NEXT_KEYS,
+    FOCUS_PREVIOUS_KEYS, FOCUS_STICK_THRESHOLD,
+};
+
+/// Moves focus between the focusable buttons with the arrow keys, D-pad or left stick, in reading
+/// order. The stick moves focus once per push rather than every frame it's held.
+pub fn navigate_focus(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    focusable_query: Query<(Entity, &GlobalTransform, Has<Focused>), With<Focusable>>,
+    mut stick_held: Local<bool>,
+) {
+    let mut step = 0;
+    if keyboard_input.any_just_pressed(FOCUS_PREVIOUS_KEYS)
+        || any_just_pressed(&gamepad_buttons, GamepadButtonType::DPadUp)
+        || any_just_pressed(&gamepad_buttons, GamepadButtonType::DPadLeft)
+    {
+        step -= 1;
+    }
+    if keyboard_input.any_just_pressed(FOCUS_NEXT_KEYS)
+        || any_just_pressed(&gamepad_buttons, GamepadButtonType::DPadDown)
+        || any_just_pressed(&gamepad_buttons, GamepadButtonType::DPadRight)
+    {
+        step += 1;
+    }
+
+    let stick = left_stick(&gamepads, &gamepad_axes);
+    let stick_pushed = stick.length() > FOCUS_STICK_THRESHOLD;
+    if stick_pushed && !*stick_held {
+        // Up the screen is back through the buttons, like the D-pad.
+        step += if stick.x.abs() > stick.y.abs() {
+            stick.x.signum() as i32
+        } else {
+            -stick.y.signum() as i32
+        };
+    }
+    *stick_held = stick_pushed;
+
+    move_focus(&mut commands, &focusable_query, step);
+}
+
+/// Moves focus `step` buttons along, wrapping at either end. With nothing focused yet, the
+/// first step lands on the first or last button.
+fn move_focus(
+    commands: &mut Commands,
+    focusable_query: &Query<(Entity, &GlobalTransform, Has<Focused>), With<Focusable>>,
+    step: i32,
+) {
+    if step == 0 {
+        return;
+    }
+
+    let mut focusables: Vec<_> = focusable_query.iter().collect();
+    if focusables.is_empty() {
+        return;
+    }
+    // UI positions grow downwards, so this is top to bottom, then left to right.
+    focusables.sort_by(|(_, a, _), (_, b, _)| {
+        let (a, b) = (a.translation(), b.translation());
+        a.y.partial_cmp(&b.y)
+            .unwrap_or(Ordering::Equal)
+            .then(a.x.partial_cmp(&b.x).unwrap_or(Ordering::Equal))
+    });
+
+    let count = focusables.len() as i32;
+    let next = match focusables.iter().position(|(_, _, focused)| *focused) {
+        Some(current) => {
+            commands.entity(focusables[current].0).remove::<Focused>();
+            (current as i32 + step).rem_euclid(count)
+        }
+        None if step > 0 => 0,
+        None => count - 1,
+    };
+    commands.entity(focusables[next as usize].0).insert(Focused);
+}
+
+/// Presses the focused button when confirm is pressed, or the menu's back button when back is,
+/// as if it had been clicked, so each menu's own `button_interaction` handles it. The press is
+/// let go of on the next frame.
+#[allow(clippy::type_complexity)]
+pub fn activate_focus(
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    mut focusable_query: Query<(Entity, &mut Interaction, Has<Focused>, Has<FocusBack>), With<Focusable>>,
+    mut pressed: Local<Vec<Entity>>,
+) {
+    for entity in pressed.drain(..) {
+        if let Ok((_, mut interaction, _, _)) = focusable_query.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let confirm = keyboard_input.any_just_pressed(FOCUS_CONFIRM_KEYS)
+        || any_just_pressed(&gamepad_buttons, FOCUS_CONFIRM_BUTTON);
+    let back = keyboard_input.just_pressed(FOCUS_BACK_KEY)
+        || any_just_pressed(&gamepad_buttons, FOCUS_BACK_BUTTON);
+
+    for (entity, mut interaction, focused, focus_back) in &mut focusable_query {
+        if (confirm && focused) || (back && focus_back) {
+            *interaction = Interaction::Pressed;
+            pressed.push(entity);
+        }
+    }
+}
+
+/// Draws the focused button in its focus color, and returns buttons that lost focus to normal.
+/// Buttons the mouse is over or pressing keep the color their menu gave them.
+pub fn highlight_focus(
+    mut focusable_query: Query<(&Interaction, &Focusable, Has<Focused>, &mut BackgroundColor)>,
+) {
+    for (interaction, focusable, focused, mut background_color) in &mut focusable_query {
+        if *interaction != Interaction::None {
+            continue;
+        }
+        let color = if focused {
+            focusable.focused
+        } else {
+            focusable.normal
+        };
+        if background_color.0 != color {
+            background_color.0 = color;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::gamepad::tests::{app_with_gamepad, set_button, set_left_stick};
+
+    use super::*;
+
+    /// Three buttons stacked top to bottom, with navigation running every update.
+    fn app_with_buttons() -> (App, Gamepad, [Entity; 3]) {
+        let (mut app, gamepad) = app_with_gamepad();
+        app.add_systems(Update, (navigate_focus, apply_deferred, activate_focus).chain());
+        let buttons = [0.0, 10.0, 20.0].map(|y| {
+            app.world
+                .spawn((
+                    Focusable::new(Color::GRAY, Color::WHITE),
+                    Interaction::None,
+                    GlobalTransform::from_xyz(0.0, y, 0.0),
+                ))
+                .id()
+        });
+        (app, gamepad, buttons)
+    }
+
+    fn focused(app: &mut App) -> Vec<Entity> {
+        app.world
+            .query_filtered::<Entity, With<Focused>>()
+            .iter(&app.world)
+            .collect()
+    }
+
+    fn tap(app: &mut App, gamepad: Gamepad, button_type: GamepadButtonType) {
+        set_button(app, gamepad, button_type, true);
+        app.update();
+        set_button(app, gamepad, button_type, false);
+        app.update();
+    }
+
+    #[test]
+    fn d_pad_steps_focus_and_wraps() {
+        let (mut app, gamepad, buttons) = app_with_buttons();
+
+        tap(&mut app, gamepad, GamepadButtonType::DPadDown);
+        assert_eq!(focused(&mut app), [buttons[0]]);
+        tap(&mut app, gamepad, GamepadButtonType::DPadRight);
+        assert_eq!(focused(&mut app), [buttons[1]]);
+        tap(&mut app, gamepad, GamepadButtonType::DPadUp);
+        tap(&mut app, gamepad, GamepadButtonType::DPadLeft);
+        assert_eq!(focused(&mut app), [buttons[2]]);
+        tap(&mut app, gamepad, GamepadButtonType::DPadDown);
+        assert_eq!(focused(&mut app), [buttons[0]]);
+    }
+
+    #[test]
+    fn d_pad_up_with_nothing_focused_lands_on_the_last_button() {
+        let (mut app, gamepad, buttons) = app_with_buttons();
+
+        tap(&mut app, gamepad, GamepadButtonType::DPadUp);
+        assert_eq!(focused(&mut app), [buttons[2]]);
+    }
+
+    #[test]
+    fn stick_steps_focus_once_per_push() {
+        let (mut app, gamepad, buttons) = app_with_buttons();
+
+        set_left_stick(&mut app, gamepad, Vec2::new(0.0, -1.0));
+        app.update();
+        app.update();
+        assert_eq!(focused(&mut app), [buttons[0]]);
+
+        set_left_stick(&mut app, gamepad, Vec2::ZERO);
+        app.update();
+        set_left_stick(&mut app, gamepad, Vec2::new(0.0, 1.0));
+        app.update();
+        assert_eq!(focused(&mut app), [buttons[2]]);
+
+        set_left_stick(&mut app, gamepad, Vec2::ZERO);
+        app.update();
+        set_left_stick(&mut app, gamepad, Vec2::new(1.0, 0.0));
+        app.update();
+        assert_eq!(focused(&mut app), [buttons[0]]);
+    }
+
+    #[test]
+    fn south_presses_the_focused_button_for_one_frame() {
+        let (mut app, gamepad, buttons) = app_with_buttons();
+        app.world.entity_mut(buttons[1]).insert(Focused);
+
+        set_button(&mut app, gamepad, FOCUS_CONFIRM_BUTTON, true);
+        app.update();
+        let interactions = buttons.map(|button| *app.world.get::<Interaction>(button).unwrap());
+        assert_eq!(
+            interactions,
+            [Interaction::None, Interaction::Pressed, Interaction::None]
+        );
+
+        app.update();
+        assert_eq!(
+            *app.world.get::<Interaction>(buttons[1]).unwrap(),
+            Interaction::None
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use bevy::prelude::{Component, Timer};
+
+/// Holds the toasts, stacked in the corner of the screen.
+#[derive(Component)]
+pub struct ToastList;
+
+/// Tells the player an achievement was unlocked, until the timer runs out.
+#[derive(Component)]
+pub struct AchievementToast {
+    pub timer: Timer,
+}
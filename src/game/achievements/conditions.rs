@@ -0,0 +1,69 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::{
+    enemies::components::EnemyKind, inventory::components::Inventory,
+    player::components::Player, stats::resources::RunStats,
+};
+
+/// What it takes to unlock an achievement. Kill counts add up over every run; the rest have
+/// to happen within a single run.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum Condition {
+    /// Kill this many enemies, or only those of one kind.
+    Kills { enemy: Option<EnemyKind>, count: u32 },
+    BossKills { count: u32 },
+    /// Reach a level, optionally without ever being hurt on the way.
+    Level {
+        level: usize,
+        #[serde(default)]
+        without_damage: bool,
+    },
+    Survive { seconds: f32 },
+    /// Get any weapon to its max rank.
+    MaxWeapon,
+    /// Evolve any weapon.
+    Evolve,
+}
+
+/// Achievement progress that builds up over every run, kept in the save file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementProgress {
+    pub kills_by_kind: BTreeMap<EnemyKind, u32>,
+    pub boss_kills: u32,
+}
+
+impl Condition {
+    pub fn is_met(
+        &self,
+        progress: &AchievementProgress,
+        run_stats: &RunStats,
+        player: &Player,
+        inventory: &Inventory,
+    ) -> bool {
+        match *self {
+            Condition::Kills { enemy, count } => {
+                let kills: u32 = progress
+                    .kills_by_kind
+                    .iter()
+                    .filter(|(kind, _)| enemy.map_or(true, |enemy| enemy == **kind))
+                    .map(|(_, kills)| kills)
+                    .sum();
+                kills >= count
+            }
+            Condition::BossKills { count } => progress.boss_kills >= count,
+            Condition::Level {
+                level,
+                without_damage,
+            } => player.lv >= level && (!without_damage || run_stats.damage_taken == 0.0),
+            Condition::Survive { seconds } => run_stats.time >= seconds,
+            Condition::MaxWeapon => inventory
+                .weapons
+                .iter()
+                .any(|(weapon, rank)| *rank >= weapon.max_rank()),
+            Condition::Evolve => !inventory.evolved.is_empty(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+
+use crate::{game::inventory::items::Weapon, power_ups::items::PowerUp};
+
+use super::conditions::Condition;
+
+/// Something an achievement makes available. Until then it can't be picked, found or bought.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Unlock {
+    /// A character, by id.
+    Character(String),
+    Weapon(Weapon),
+    PowerUp(PowerUp),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Achievement {
+    /// Stays the same when the name changes, for the save file.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub condition: Condition,
+    #[serde(default)]
+    pub unlocks: Vec<Unlock>,
+}
+
+/// All achievements, loaded from an `.achievements.ron` asset.
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "9d3b6e2f-8a41-4c7d-b5e0-2f6a1c9d4b83"]
+pub struct Achievements {
+    pub achievements: Vec<Achievement>,
+}
+
+impl Achievements {
+    /// The achievement that unlocks `unlock`, to tell the player how to get it.
+    pub fn unlocking(&self, unlock: &Unlock) -> Option<&Achievement> {
+        self.achievements
+            .iter()
+            .find(|achievement| achievement.unlocks.contains(unlock))
+    }
+}
+
+#[derive(Default)]
+pub struct AchievementsLoader;
+
+impl AssetLoader for AchievementsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let achievements = ron::de::from_bytes::<Achievements>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(achievements));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["achievements.ron"]
+    }
+}
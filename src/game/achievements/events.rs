@@ -0,0 +1,7 @@
+use bevy::prelude::Event;
+
+use super::definitions::Achievement;
+
+/// Sent once when an achievement is unlocked, after it has been saved.
+#[derive(Event)]
+pub struct AchievementUnlockedEvent(pub Achievement);
@@ -0,0 +1,50 @@
+mod components;
+pub mod conditions;
+pub mod definitions;
+pub mod events;
+pub mod resources;
+mod styles;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{
+    definitions::{Achievements, AchievementsLoader},
+    events::AchievementUnlockedEvent,
+    systems::{
+        check_achievements, despawn_toasts, load_achievements, record_progress, spawn_toast_list,
+        spawn_toasts, tick_toasts,
+    },
+};
+
+use super::states::GameState;
+
+/// How long an unlock toast stays on screen, in seconds.
+pub const ACHIEVEMENT_TOAST_DURATION: f32 = 4.0;
+
+/// Tracks progress towards the achievements defined in `.achievements.ron` assets, unlocks
+/// them and what they unlock in the save file, and shows a toast for each one.
+pub struct AchievementsPlugin;
+
+impl Plugin for AchievementsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Achievements>()
+            .init_asset_loader::<AchievementsLoader>()
+            .add_event::<AchievementUnlockedEvent>()
+            .add_systems(Startup, load_achievements)
+            .add_systems(OnEnter(AppState::Game), spawn_toast_list)
+            .add_systems(
+                Update,
+                (record_progress, check_achievements)
+                    .chain()
+                    .run_if(in_state(GameState::Running)),
+            )
+            .add_systems(
+                Update,
+                (spawn_toasts, tick_toasts).run_if(in_state(AppState::Game)),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_toasts);
+    }
+}
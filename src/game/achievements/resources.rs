@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+use super::definitions::Achievements;
+
+#[derive(Resource)]
+pub struct AchievementsHandle(pub Handle<Achievements>);
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+
+pub const TOAST_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.8);
+pub const TOAST_TITLE_COLOR: Color = Color::GOLD;
+
+pub const TOAST_LIST_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.position_type = PositionType::Absolute;
+    style.top = Val::Px(16.0);
+    style.right = Val::Px(16.0);
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = AlignItems::FlexEnd;
+    style.row_gap = Val::Px(8.0);
+    style
+};
+
+pub const TOAST_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = AlignItems::FlexStart;
+    style.padding = UiRect::all(Val::Px(12.0));
+    style.row_gap = Val::Px(4.0);
+    style
+};
+
+pub fn get_toast_title_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 24.0, TOAST_TITLE_COLOR)
+}
+
+pub fn get_toast_description_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 18.0, Color::WHITE)
+}
+
+fn get_text(asset_server: &Res<AssetServer>, text: &str, font_size: f32, color: Color) -> Text {
+    Text::from_section(
+        text,
+        TextStyle {
+            font: asset_server.load("fonts/Davidfont.otf"),
+            font_size,
+            color,
+        },
+    )
+}
@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+use crate::{
+    game::{
+        enemies::events::EnemyDeathEvent, inventory::components::Inventory,
+        player::components::Player, stats::resources::RunStats,
+    },
+    save::resources::SaveData,
+};
+
+use super::{
+    definitions::Achievements,
+    components::{AchievementToast, ToastList},
+    events::AchievementUnlockedEvent,
+    resources::AchievementsHandle,
+    styles::{
+        get_toast_description_text, get_toast_title_text, TOAST_BACKGROUND_COLOR,
+        TOAST_LIST_STYLE, TOAST_STYLE,
+    },
+    ACHIEVEMENT_TOAST_DURATION,
+};
+
+pub fn load_achievements(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(AchievementsHandle(
+        asset_server.load("achievements/base.achievements.ron"),
+    ));
+}
+
+/// Adds kills to the progress kept across runs. It's written to disk with the rest of the
+/// save when the run ends.
+pub fn record_progress(
+    mut enemy_death_event_reader: EventReader<EnemyDeathEvent>,
+    mut save_data: ResMut<SaveData>,
+) {
+    for event in &mut enemy_death_event_reader {
+        let progress = &mut save_data.achievement_progress;
+        *progress.kills_by_kind.entry(event.kind).or_default() += 1;
+        if event.boss {
+            progress.boss_kills += 1;
+        }
+    }
+}
+
+/// Unlocks every achievement whose condition has been met, along with what it unlocks, and
+/// saves straight away so an unlock is never lost.
+pub fn check_achievements(
+    player_query: Query<(&Player, &Inventory)>,
+    achievements_handle: Res<AchievementsHandle>,
+    achievements: Res<Assets<Achievements>>,
+    run_stats: Res<RunStats>,
+    mut save_data: ResMut<SaveData>,
+    mut achievement_unlocked_event_writer: EventWriter<AchievementUnlockedEvent>,
+) {
+    let Some(achievements) = achievements.get(&achievements_handle.0) else {
+        return;
+    };
+    let Ok((player, inventory)) = player_query.get_single() else {
+        return;
+    };
+
+    let unlocked: Vec<_> = achievements
+        .achievements
+        .iter()
+        .filter(|achievement| {
+            !save_data.achievements.contains(&achievement.id)
+                && achievement.condition.is_met(
+                    &save_data.achievement_progress,
+                    &run_stats,
+                    player,
+                    inventory,
+                )
+        })
+        .collect();
+    if unlocked.is_empty() {
+        return;
+    }
+
+    for achievement in unlocked {
+        save_data.achievements.insert(achievement.id.clone());
+        for unlock in &achievement.unlocks {
+            save_data.unlock(unlock);
+        }
+        achievement_unlocked_event_writer.send(AchievementUnlockedEvent(achievement.clone()));
+    }
+    save_data.save();
+}
+
+pub fn spawn_toast_list(mut commands: Commands) {
+    commands.spawn((
+        NodeBundle {
+            style: TOAST_LIST_STYLE,
+            ..default()
+        },
+        ToastList {},
+        Name::from("Achievement Toasts"),
+    ));
+}
+
+pub fn spawn_toasts(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut achievement_unlocked_event_reader: EventReader<AchievementUnlockedEvent>,
+    toast_list_query: Query<Entity, With<ToastList>>,
+) {
+    let Ok(toast_list_entity) = toast_list_query.get_single() else {
+        return;
+    };
+
+    for AchievementUnlockedEvent(achievement) in &mut achievement_unlocked_event_reader {
+        commands.entity(toast_list_entity).with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: TOAST_STYLE,
+                        background_color: TOAST_BACKGROUND_COLOR.into(),
+                        ..default()
+                    },
+                    AchievementToast {
+                        timer: Timer::from_seconds(ACHIEVEMENT_TOAST_DURATION, TimerMode::Once),
+                    },
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_toast_title_text(
+                            &asset_server,
+                            &format!("Achievement: {}", achievement.name),
+                        ),
+                        ..default()
+                    });
+                    parent.spawn(TextBundle {
+                        text: get_toast_description_text(&asset_server, &achievement.description),
+                        ..default()
+                    });
+                });
+        });
+    }
+}
+
+/// Toasts count down in real time, so they still go away while the game is paused.
+pub fn tick_toasts(
+    mut commands: Commands,
+    mut toast_query: Query<(Entity, &mut AchievementToast)>,
+    time: Res<Time>,
+) {
+    for (entity, mut toast) in &mut toast_query {
+        toast.timer.tick(time.delta());
+        if toast.timer.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
+
+pub fn despawn_toasts(mut commands: Commands, toast_list_query: Query<Entity, With<ToastList>>) {
+    if let Ok(toast_list_entity) = toast_list_query.get_single() {
+        commands.entity(toast_list_entity).despawn_recursive();
+    }
+}
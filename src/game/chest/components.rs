@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+
+use crate::game::upgrades::pool::Upgrade;
+
+#[derive(Component)]
+pub struct ChestMenu;
+
+#[derive(Component)]
+pub struct ChestMenuButton;
+
+#[derive(Component)]
+pub struct ChestMenuButtonText;
+
+/// A reward slot that cycles through upgrades until its reveal timer runs out.
+#[derive(Component)]
+pub struct ChestRewardSlot {
+    pub reward: Upgrade,
+    pub reveal: Timer,
+    pub spin: Timer,
+    pub shown: usize,
+    pub revealed: bool,
+}
+
+impl ChestRewardSlot {
+    pub fn new(reward: Upgrade, reveal_after: f32, spin_interval: f32) -> Self {
+        ChestRewardSlot {
+            reward,
+            reveal: Timer::from_seconds(reveal_after, TimerMode::Once),
+            spin: Timer::from_seconds(spin_interval, TimerMode::Repeating),
+            shown: 0,
+            revealed: false,
+        }
+    }
+
+    /// Stops the slot on its reward the next time it is updated.
+    pub fn skip(&mut self) {
+        let duration = self.reveal.duration();
+        self.reveal.set_elapsed(duration);
+    }
+}
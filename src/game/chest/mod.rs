@@ -0,0 +1,33 @@
+mod components;
+mod styles;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::{game::states::GameState, states::AppState};
+
+use self::systems::{button_interaction, despawn_chest_menu, spawn_chest_menu, spin_reward_slots};
+
+/// How many rewards a chest can hold, with the weight of each.
+pub const CHEST_REWARD_COUNTS: [(usize, u32); 3] = [(1, 75), (3, 20), (5, 5)];
+/// How long the first slot spins, and how much longer each following slot spins.
+pub const CHEST_REVEAL_DELAY: f32 = 1.0;
+pub const CHEST_REVEAL_STAGGER: f32 = 0.4;
+/// How often a spinning slot shows a different upgrade.
+pub const CHEST_SPIN_INTERVAL: f32 = 0.08;
+
+pub struct ChestMenuPlugin;
+
+impl Plugin for ChestMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Chest),
+            spawn_chest_menu.run_if(in_state(AppState::Game)),
+        )
+        .add_systems(
+            Update,
+            (spin_reward_slots, button_interaction).run_if(in_state(GameState::Chest)),
+        )
+        .add_systems(OnExit(GameState::Chest), despawn_chest_menu);
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+
+pub const NORMAL_BUTTON_COLOR: Color = Color::hsla(0.0, 0.0, 0.15, 1.0);
+pub const HOVERED_BUTTON_COLOR: Color = Color::hsla(0.0, 0.0, 0.25, 1.0);
+pub const PRESSED_BUTTON_COLOR: Color = Color::hsla(125.0, 0.75, 0.5, 1.0);
+
+pub const SPINNING_REWARD_COLOR: Color = Color::hsla(0.0, 0.0, 0.6, 1.0);
+pub const REVEALED_REWARD_COLOR: Color = Color::GOLD;
+
+pub const CHEST_MENU_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.position_type = PositionType::Absolute;
+    style.width = Val::Percent(80.0);
+    style.height = Val::Percent(80.0);
+    style.justify_self = JustifySelf::Center;
+    style.align_self = AlignSelf::Center;
+    style.flex_direction = FlexDirection::Column;
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+    style.row_gap = Val::Px(16.0);
+    style
+};
+
+pub const CHEST_REWARDS_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Row;
+    style.justify_content = JustifyContent::Center;
+    style.column_gap = Val::Px(8.0);
+    style
+};
+
+pub const CHEST_REWARD_SLOT_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(200.0);
+    style.height = Val::Px(80.0);
+    style.align_items = AlignItems::Center;
+    style.justify_content = JustifyContent::Center;
+    style
+};
+
+pub const CHEST_MENU_TRANSFORM: Transform = {
+    let mut transform = Transform::IDENTITY;
+    transform.translation.z = 1000.0;
+    transform
+};
+
+pub const CHEST_BUTTON_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(200.0);
+    style.height = Val::Px(80.0);
+    style.align_items = AlignItems::Center;
+    style.justify_content = JustifyContent::Center;
+    style
+};
+
+pub const CHEST_MENU_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.5);
+pub const CHEST_REWARD_SLOT_BACKGROUND_COLOR: Color = Color::hsla(0.0, 0.0, 0.1, 1.0);
+
+pub fn get_button_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 32.0, Color::hsla(0.0, 0.0, 0.9, 1.0))
+}
+
+pub fn get_reward_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 24.0, SPINNING_REWARD_COLOR)
+}
+
+fn get_text(asset_server: &Res<AssetServer>, text: &str, font_size: f32, color: Color) -> Text {
+    Text {
+        sections: vec![TextSection::new(
+            text,
+            TextStyle {
+                font: asset_server.load("fonts/Davidfont.otf"),
+                font_size,
+                color,
+            },
+        )],
+        alignment: TextAlignment::Center,
+        ..default()
+    }
+}
@@ -0,0 +1,233 @@
+use bevy::prelude::*;
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+
+use crate::focus::components::{FocusBack, Focusable, Focused};
+use crate::save::resources::SaveData;
+use crate::game::{
+    inventory::{components::Inventory, evolutions::Evolutions, resources::EvolutionsHandle},
+    player::{
+        components::Player,
+        stats::{PlayerStats, Stat},
+    },
+    resources::GameRng,
+    states::GameState,
+    upgrades::{events::ApplyUpgradeEvent, pool::Upgrade},
+};
+
+use super::components::{ChestMenu, ChestMenuButton, ChestMenuButtonText, ChestRewardSlot};
+use super::styles::{
+    get_button_text, get_reward_text, CHEST_BUTTON_STYLE, CHEST_MENU_BACKGROUND_COLOR,
+    CHEST_MENU_STYLE, CHEST_MENU_TRANSFORM, CHEST_REWARDS_STYLE, CHEST_REWARD_SLOT_BACKGROUND_COLOR,
+    CHEST_REWARD_SLOT_STYLE, HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR,
+    REVEALED_REWARD_COLOR,
+};
+use super::{CHEST_REVEAL_DELAY, CHEST_REVEAL_STAGGER, CHEST_REWARD_COUNTS, CHEST_SPIN_INTERVAL};
+
+pub fn spawn_chest_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    player_query: Query<(&Inventory, &PlayerStats), With<Player>>,
+    evolutions_handle: Res<EvolutionsHandle>,
+    evolutions: Res<Assets<Evolutions>>,
+    save_data: Res<SaveData>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let rng = &mut game_rng.rng;
+    let rewards = match player_query.get_single() {
+        Ok((inventory, stats)) => Upgrade::roll_chest(
+            roll_reward_count(stats.get(Stat::Luck), rng),
+            inventory,
+            &save_data.unlocked_weapons,
+            evolutions
+                .get(&evolutions_handle.0)
+                .into_iter()
+                .flat_map(|evolutions| evolutions.eligible(inventory)),
+            rng,
+        ),
+        Err(_) => Vec::new(),
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                ..default()
+            },
+            ChestMenu {},
+            Name::from("Chest Menu Container"),
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    NodeBundle {
+                        style: CHEST_MENU_STYLE,
+                        background_color: CHEST_MENU_BACKGROUND_COLOR.into(),
+                        ..default()
+                    },
+                    Name::from("Chest Menu"),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_button_text(&asset_server, "Treasure!"),
+                        ..default()
+                    });
+
+                    parent
+                        .spawn(NodeBundle {
+                            style: CHEST_REWARDS_STYLE,
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for (index, reward) in rewards.into_iter().enumerate() {
+                                spawn_reward_slot(
+                                    parent,
+                                    &asset_server,
+                                    ChestRewardSlot::new(
+                                        reward,
+                                        CHEST_REVEAL_DELAY + index as f32 * CHEST_REVEAL_STAGGER,
+                                        CHEST_SPIN_INTERVAL,
+                                    ),
+                                );
+                            }
+                        });
+
+                    parent
+                        .spawn((
+                            ButtonBundle {
+                                style: CHEST_BUTTON_STYLE,
+                                transform: CHEST_MENU_TRANSFORM,
+                                ..default()
+                            },
+                            ChestMenuButton {},
+                            // The only button, so it starts focused.
+                            Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                            Focused,
+                            FocusBack,
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle {
+                                    text: get_button_text(&asset_server, "Skip"),
+                                    ..default()
+                                },
+                                ChestMenuButtonText {},
+                            ));
+                        });
+                });
+        });
+}
+
+/// Rolls how many rewards a chest holds. Luck makes the bigger chests more likely.
+fn roll_reward_count(luck: f32, rng: &mut impl Rng) -> usize {
+    let weights = CHEST_REWARD_COUNTS.iter().map(|(count, weight)| {
+        if *count > 1 {
+            *weight as f32 * luck.max(0.0)
+        } else {
+            *weight as f32
+        }
+    });
+    WeightedIndex::new(weights)
+        .map(|index| CHEST_REWARD_COUNTS[index.sample(rng)].0)
+        .unwrap_or(1)
+}
+
+fn spawn_reward_slot(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    slot: ChestRewardSlot,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: CHEST_REWARD_SLOT_STYLE,
+            background_color: CHEST_REWARD_SLOT_BACKGROUND_COLOR.into(),
+            ..default()
+        })
+        .with_children(|parent| {
+            parent.spawn((
+                TextBundle {
+                    text: get_reward_text(asset_server, &Upgrade::ALL[0].to_string()),
+                    ..default()
+                },
+                slot,
+            ));
+        });
+}
+
+pub fn despawn_chest_menu(mut commands: Commands, chest_menu_query: Query<Entity, With<ChestMenu>>) {
+    if let Ok(chest_menu_entity) = chest_menu_query.get_single() {
+        commands.entity(chest_menu_entity).despawn_recursive();
+    }
+}
+
+/// Cycles each slot through the upgrade pool until it stops on its reward, then lets the
+/// player collect once every slot has stopped.
+pub fn spin_reward_slots(
+    mut slot_query: Query<(&mut ChestRewardSlot, &mut Text)>,
+    mut button_text_query: Query<&mut Text, (With<ChestMenuButtonText>, Without<ChestRewardSlot>)>,
+    time: Res<Time>,
+) {
+    let mut all_revealed = true;
+
+    for (mut slot, mut text) in &mut slot_query {
+        if slot.revealed {
+            continue;
+        }
+
+        slot.reveal.tick(time.delta());
+        if slot.reveal.finished() {
+            slot.revealed = true;
+            text.sections[0].value = slot.reward.to_string();
+            text.sections[0].style.color = REVEALED_REWARD_COLOR;
+            continue;
+        }
+
+        all_revealed = false;
+        slot.spin.tick(time.delta());
+        if slot.spin.just_finished() {
+            slot.shown = (slot.shown + 1) % Upgrade::ALL.len();
+            text.sections[0].value = Upgrade::ALL[slot.shown].to_string();
+        }
+    }
+
+    if all_revealed {
+        if let Ok(mut button_text) = button_text_query.get_single_mut() {
+            button_text.sections[0].value = "Collect".to_string();
+        }
+    }
+}
+
+/// While slots are still spinning the button stops them all; afterwards it applies every reward.
+#[allow(clippy::type_complexity)]
+pub fn button_interaction(
+    mut button_query: Query<(&Interaction, &mut BackgroundColor), (With<ChestMenuButton>, Changed<Interaction>)>,
+    mut slot_query: Query<&mut ChestRewardSlot>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut apply_upgrade_event_writer: EventWriter<ApplyUpgradeEvent>,
+) {
+    for (interaction, mut background_color) in button_query.iter_mut() {
+        match *interaction {
+            Interaction::Pressed => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                if slot_query.iter().all(|slot| slot.revealed) {
+                    for slot in &slot_query {
+                        apply_upgrade_event_writer.send(ApplyUpgradeEvent(slot.reward));
+                    }
+                    next_game_state.set(GameState::Running);
+                } else {
+                    for mut slot in &mut slot_query {
+                        slot.skip();
+                    }
+                }
+            }
+            Interaction::Hovered => *background_color = HOVERED_BUTTON_COLOR.into(),
+            _ => *background_color = NORMAL_BUTTON_COLOR.into(),
+        }
+    }
+}
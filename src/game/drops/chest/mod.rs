@@ -0,0 +1,26 @@
+pub mod components;
+pub mod events;
+pub mod systems;
+
+use bevy::prelude::*;
+
+use crate::game::states::GameState;
+
+use self::{
+    events::OpenChestEvent,
+    systems::{player_collect, spawn_chest},
+};
+
+pub const CHEST_COLOR: Color = Color::rgb(0.55, 0.3, 0.1);
+pub const CHEST_TRIM_COLOR: Color = Color::GOLD;
+
+pub struct ChestPlugin;
+
+impl Plugin for ChestPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<OpenChestEvent>().add_systems(
+            Update,
+            (spawn_chest, player_collect).run_if(in_state(GameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use bevy::{
+    prelude::*,
+    sprite::MaterialMesh2dBundle,
+};
+
+use crate::game::{
+    drops::{components::Drop, events::DropSpawnEvent, tables::DropKind},
+    player::components::{PickupRadius, Player},
+};
+
+use super::{components::ChestPickup, events::OpenChestEvent, CHEST_COLOR, CHEST_TRIM_COLOR};
+
+pub fn spawn_chest(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut drop_spawn_event_reader: EventReader<DropSpawnEvent>,
+) {
+    for event in &mut drop_spawn_event_reader {
+        if event.kind == DropKind::Chest {
+            spawn_chest_pickup(&mut commands, &mut meshes, &mut materials, event.position);
+        }
+    }
+}
+
+/// Spawns a chest built from a box with a gold band and lock.
+pub fn spawn_chest_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    position: Vec3,
+) -> Entity {
+    let material = materials.add(ColorMaterial::from(CHEST_COLOR));
+    let trim_material = materials.add(ColorMaterial::from(CHEST_TRIM_COLOR));
+    let body = meshes.add(shape::Quad::new(Vec2::new(12.0, 9.0)).into());
+    let band = meshes.add(shape::Quad::new(Vec2::new(12.0, 1.5)).into());
+    let lock = meshes.add(shape::Quad::new(Vec2::new(2.5, 3.0)).into());
+
+    commands
+        .spawn((
+            Drop {},
+            ChestPickup {},
+            Name::from("Chest Pickup"),
+            SpatialBundle::from_transform(Transform::from_translation(position)),
+        ))
+        .with_children(|parent| {
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: body.into(),
+                material,
+                ..default()
+            });
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: band.into(),
+                material: trim_material.clone(),
+                transform: Transform::from_xyz(0.0, 1.5, 0.1),
+                ..default()
+            });
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: lock.into(),
+                material: trim_material,
+                transform: Transform::from_xyz(0.0, 0.5, 0.2),
+                ..default()
+            });
+        })
+        .id()
+}
+
+/// Opens at most one chest per frame, so chests picked up together are each revealed in turn.
+#[allow(clippy::type_complexity)]
+pub fn player_collect(
+    mut commands: Commands,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<ChestPickup>)>,
+    chest_query: Query<(Entity, &Transform), (With<ChestPickup>, Without<Player>)>,
+    mut open_chest_event_writer: EventWriter<OpenChestEvent>,
+) {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (chest_entity, chest_transform) in &chest_query {
+            if pickup_radius.collects(player_transform.translation, chest_transform.translation) {
+                commands.entity(chest_entity).despawn_recursive();
+                open_chest_event_writer.send(OpenChestEvent);
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct CoinPickup {
+    pub value: u32,
+}
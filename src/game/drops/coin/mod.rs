@@ -0,0 +1,29 @@
+pub mod components;
+pub mod events;
+pub mod systems;
+
+use bevy::prelude::*;
+
+use crate::game::states::GameState;
+
+use self::{
+    events::SendCoinEvent,
+    systems::{bank_coins, player_collect, spawn_coin},
+};
+
+pub const COIN_PICKUP_VALUE: u32 = 1;
+pub const COIN_COLOR: Color = Color::GOLD;
+pub const COIN_RIM_COLOR: Color = Color::rgb(0.7, 0.5, 0.0);
+
+/// Coins are added to the save as soon as they're picked up, so they're kept however the
+/// run ends. The save is written when the game is left.
+pub struct CoinPlugin;
+
+impl Plugin for CoinPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SendCoinEvent>().add_systems(
+            Update,
+            (spawn_coin, player_collect, bank_coins).run_if(in_state(GameState::Running)),
+        );
+    }
+}
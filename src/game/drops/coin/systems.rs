@@ -0,0 +1,87 @@
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+
+use crate::{
+    game::{
+        drops::{components::Drop, events::DropSpawnEvent, tables::DropKind},
+        player::components::{PickupRadius, Player},
+    },
+    save::resources::SaveData,
+};
+
+use super::{
+    components::CoinPickup, events::SendCoinEvent, COIN_COLOR, COIN_PICKUP_VALUE, COIN_RIM_COLOR,
+};
+
+pub fn spawn_coin(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut drop_spawn_event_reader: EventReader<DropSpawnEvent>,
+) {
+    for event in &mut drop_spawn_event_reader {
+        if event.kind == DropKind::Coin {
+            spawn_coin_pickup(&mut commands, &mut meshes, &mut materials, event.position);
+        }
+    }
+}
+
+/// Spawns a gold coin with a darker rim.
+pub fn spawn_coin_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    position: Vec3,
+) -> Entity {
+    let rim = meshes.add(shape::Circle::new(4.5).into());
+    let face = meshes.add(shape::Circle::new(3.5).into());
+
+    commands
+        .spawn((
+            Drop {},
+            CoinPickup {
+                value: COIN_PICKUP_VALUE,
+            },
+            Name::from("Coin Pickup"),
+            SpatialBundle::from_transform(Transform::from_translation(position)),
+        ))
+        .with_children(|parent| {
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: rim.into(),
+                material: materials.add(ColorMaterial::from(COIN_RIM_COLOR)),
+                ..default()
+            });
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: face.into(),
+                material: materials.add(ColorMaterial::from(COIN_COLOR)),
+                transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                ..default()
+            });
+        })
+        .id()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn player_collect(
+    mut commands: Commands,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<CoinPickup>)>,
+    coin_query: Query<(Entity, &CoinPickup, &Transform), Without<Player>>,
+    mut send_coin_event_writer: EventWriter<SendCoinEvent>,
+) {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (coin_entity, coin, coin_transform) in &coin_query {
+            if pickup_radius.collects(player_transform.translation, coin_transform.translation) {
+                commands.entity(coin_entity).despawn_recursive();
+                send_coin_event_writer.send(SendCoinEvent(coin.value));
+            }
+        }
+    }
+}
+
+pub fn bank_coins(
+    mut send_coin_event_reader: EventReader<SendCoinEvent>,
+    mut save_data: ResMut<SaveData>,
+) {
+    for event in send_coin_event_reader.iter() {
+        save_data.coins += event.0;
+    }
+}
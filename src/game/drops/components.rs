@@ -1,4 +1,10 @@
 use bevy::prelude::Component;
 
 #[derive(Component)]
-pub struct Drop;
\ No newline at end of file
+pub struct Drop;
+
+/// Pulls a drop towards the player from any distance, faster the longer it has been pulled.
+#[derive(Component)]
+pub struct Magnetized {
+    pub speed: f32,
+}
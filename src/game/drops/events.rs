@@ -0,0 +1,10 @@
+use bevy::prelude::{Event, Vec3};
+
+use super::tables::DropKind;
+
+/// Asks the plugin responsible for `kind` to spawn it at `position`.
+#[derive(Event)]
+pub struct DropSpawnEvent {
+    pub kind: DropKind,
+    pub position: Vec3,
+}
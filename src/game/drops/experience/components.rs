@@ -1,9 +1,9 @@
 use std::fmt::Display;
 
-use bevy::prelude::Component;
-use rand::{Rng, thread_rng};
+use bevy::prelude::{Component, Deref, DerefMut};
+use serde::{Deserialize, Serialize};
 
-#[derive(Component, Copy, Clone)]
+#[derive(Component, Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Experience {
      X,
     Y,
@@ -11,14 +11,15 @@ pub enum Experience {
 }
 
 impl Experience {
-    pub fn generate() -> Self {
-        let mut rng = thread_rng();
+    pub const ALL: [Experience; 3] = [Experience::X, Experience::Y, Experience::Z];
 
-        match rng.gen_range(1..=10000) {
-            x if x % 10000 == 0 => Experience::Z,
-            y if y % 100 == 0 => Experience::Y,
-            _ => Experience::X,
-        }
+    /// The largest tier whose base value fits in `value`, used to pick a merged gem's sprite.
+    pub fn for_value(value: f32) -> Self {
+        Experience::ALL
+            .into_iter()
+            .rev()
+            .find(|experience| value >= Into::<f32>::into(*experience))
+            .unwrap_or(Experience::X)
     }
 }
 
@@ -40,4 +41,12 @@ impl Into<f32> for Experience {
             Experience::Z => 10000.0,
         }
     }
-}
\ No newline at end of file
+}
+
+/// How much experience a gem is worth. Starts at its tier's value and grows when gems merge.
+#[derive(Component, Debug, Deref, DerefMut)]
+pub struct ExperienceValue(pub f32);
+
+/// Text above a merged gem showing its total value.
+#[derive(Component)]
+pub struct ExperienceLabel;
@@ -1,7 +1,4 @@
-use bevy::prelude::{Event, Transform};
-
-#[derive(Event)]
-pub struct ExperienceSpawnEvent(pub Transform);
+use bevy::prelude::Event;
 
 #[derive(Event)]
 pub struct SendExperienceEvent(pub f32);
\ No newline at end of file
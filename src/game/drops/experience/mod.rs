@@ -1,26 +1,48 @@
-mod components;
+pub mod components;
 pub mod events;
-mod systems;
+pub mod resources;
+pub mod systems;
 
-use bevy::prelude::*;
+use std::time::Duration;
+
+use bevy::{prelude::*, time::common_conditions::on_timer};
 
 use crate::game::states::GameState;
 
 use self::{
-    events::{ExperienceSpawnEvent, SendExperienceEvent},
-    systems::{player_collect, spawn_experience, pulse_experience},
+    events::SendExperienceEvent,
+    resources::ExperienceMerging,
+    systems::{
+        load_experience_atlases, merge_experience, player_collect, pulse_experience,
+        spawn_experience, update_experience_labels,
+    },
 };
 
+pub const EXPERIENCE_MERGE_CAP: usize = 200;
+pub const EXPERIENCE_MERGE_RADIUS: f32 = 48.0;
+pub const EXPERIENCE_MERGE_INTERVAL: Duration = Duration::from_millis(500);
+pub const EXPERIENCE_LABEL_FONT_SIZE: f32 = 96.0;
+
 pub struct ExperiencePlugin;
 
 impl Plugin for ExperiencePlugin {
     fn build(&self, app: &mut App) {
-        app.add_event::<ExperienceSpawnEvent>()
-            .add_event::<SendExperienceEvent>()
+        app.add_event::<SendExperienceEvent>()
+            .init_resource::<ExperienceMerging>()
+            .add_systems(Startup, load_experience_atlases)
             .add_systems(
-            Update,
-            (spawn_experience, pulse_experience, player_collect)
-                .run_if(in_state(GameState::Running)),
-        );
+                Update,
+                (
+                    spawn_experience,
+                    pulse_experience,
+                    player_collect,
+                    (
+                        merge_experience.run_if(on_timer(EXPERIENCE_MERGE_INTERVAL)),
+                        update_experience_labels,
+                    )
+                        .chain(),
+                )
+                    .run_if(in_state(GameState::Running)),
+            );
     }
 }
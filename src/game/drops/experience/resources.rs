@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+
+use super::{components::Experience, EXPERIENCE_MERGE_CAP, EXPERIENCE_MERGE_RADIUS};
+
+/// One texture atlas per experience tier, shared by every gem of that tier.
+#[derive(Resource)]
+pub struct ExperienceAtlases {
+    pub x: Handle<TextureAtlas>,
+    pub y: Handle<TextureAtlas>,
+    pub z: Handle<TextureAtlas>,
+}
+
+impl ExperienceAtlases {
+    pub fn get(&self, experience: Experience) -> Handle<TextureAtlas> {
+        match experience {
+            Experience::X => self.x.clone(),
+            Experience::Y => self.y.clone(),
+            Experience::Z => self.z.clone(),
+        }
+    }
+}
+
+/// Limits on how many gems can lie around before they are merged together.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct ExperienceMerging {
+    /// Above this many gems, the ones furthest from the player are merged into one.
+    pub cap: usize,
+    /// Gems within roughly this distance of each other are merged.
+    pub radius: f32,
+}
+
+impl Default for ExperienceMerging {
+    fn default() -> Self {
+        ExperienceMerging {
+            cap: EXPERIENCE_MERGE_CAP,
+            radius: EXPERIENCE_MERGE_RADIUS,
+        }
+    }
+}
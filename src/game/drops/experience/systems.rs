@@ -1,48 +1,87 @@
-use bevy::{prelude::*, sprite::collide_aabb::collide};
+use bevy::{prelude::*, utils::HashMap};
 
 use crate::game::{
     components::{AnimationIndices, AnimationTimer},
-    drops::components::Drop,
-    player::components::Player,
+    drops::{
+        components::{Drop, Magnetized},
+        events::DropSpawnEvent,
+        tables::DropKind,
+    },
+    player::components::{PickupRadius, Player},
 };
 
 use super::{
-    components::Experience,
-    events::{ExperienceSpawnEvent, SendExperienceEvent},
+    components::{Experience, ExperienceLabel, ExperienceValue},
+    events::SendExperienceEvent,
+    resources::{ExperienceAtlases, ExperienceMerging},
+    EXPERIENCE_LABEL_FONT_SIZE,
 };
 
-pub fn spawn_experience(
+pub fn load_experience_atlases(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
-    mut experience_spawn_event_reader: EventReader<ExperienceSpawnEvent>,
+) {
+    let mut load = |xp: Experience| {
+        let texture_handle = asset_server.load(format!("sprites/{}_experience_4_frame_64x64.png", xp));
+        texture_atlases.add(TextureAtlas::from_grid(
+            texture_handle,
+            Vec2::new(64.0, 64.0),
+            4,
+            1,
+            None,
+            None,
+        ))
+    };
+
+    commands.insert_resource(ExperienceAtlases {
+        x: load(Experience::X),
+        y: load(Experience::Y),
+        z: load(Experience::Z),
+    });
+}
 
+pub fn spawn_experience(
+    mut commands: Commands,
+    experience_atlases: Res<ExperienceAtlases>,
+    mut drop_spawn_event_reader: EventReader<DropSpawnEvent>,
 ) {
-    for event in &mut experience_spawn_event_reader {
-        let xp = Experience::generate();
-        let texture_handle = asset_server.load(format!("sprites/{}_experience_4_frame_64x64.png", xp.to_string()));
-        let texture_atlas =
-            TextureAtlas::from_grid(texture_handle, Vec2::new(64.0, 64.0), 4, 1, None, None);
-        let texture_atlas_handle = texture_atlases.add(texture_atlas);
-        let animation_indices = AnimationIndices {
-            first: 0,
-            last: 3,
-            reverse: false,
-        };
+    for event in &mut drop_spawn_event_reader {
+        if let DropKind::Experience(xp) = event.kind {
+            spawn_experience_gem(&mut commands, &experience_atlases, xp, xp.into(), event.position);
+        }
+    }
+}
+
+/// Spawns a gem worth `value`, which is more than its tier's value for a merged gem.
+pub fn spawn_experience_gem(
+    commands: &mut Commands,
+    experience_atlases: &ExperienceAtlases,
+    xp: Experience,
+    value: f32,
+    position: Vec3,
+) -> Entity {
+    let animation_indices = AnimationIndices {
+        first: 0,
+        last: 3,
+        reverse: false,
+    };
 
-        commands.spawn((
+    commands
+        .spawn((
             Drop {},
             xp,
+            ExperienceValue(value),
             SpriteSheetBundle {
-                texture_atlas: texture_atlas_handle,
+                texture_atlas: experience_atlases.get(xp),
                 sprite: TextureAtlasSprite::new(animation_indices.first),
-                transform: event.0,
+                transform: Transform::from_translation(position),
                 ..default()
             },
             animation_indices,
             AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
-        ));
-    }
+        ))
+        .id()
 }
 
 pub fn pulse_experience(
@@ -58,23 +97,138 @@ pub fn pulse_experience(
 
 pub fn player_collect(
     mut commands: Commands,
-    player_query: Query<(&Transform, &Handle<TextureAtlas>), (With<Player>, Without<Experience>)>,
-    experience_query: Query<(Entity, &Experience, &Transform), Without<Player>>,
-    texture_atlases: Res<Assets<TextureAtlas>>,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<Experience>)>,
+    experience_query: Query<(Entity, &ExperienceValue, &Transform), Without<Player>>,
     mut send_experience_event_writer: EventWriter<SendExperienceEvent>,
 ) {
-    if let Ok((player_transform, player_texture_atlas)) = player_query.get_single() {
-        for (experience_entity, experience, experience_transform) in &experience_query {
-            if collide(
-                player_transform.translation,
-                Vec2::splat(texture_atlases.get(player_texture_atlas).unwrap().size.y / 2.0),
-                experience_transform.translation,
-                Vec2::splat(1.0),
-            ) != None
-            {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (experience_entity, experience_value, experience_transform) in &experience_query {
+            if pickup_radius.collects(player_transform.translation, experience_transform.translation) {
                 commands.entity(experience_entity).despawn_recursive();
-                send_experience_event_writer.send(SendExperienceEvent((*experience).into()));
+                send_experience_event_writer.send(SendExperienceEvent(experience_value.0));
+            }
+        }
+    }
+}
+
+/// Merges gems that have piled up, first those clustered together and then, if there are still
+/// more than the cap, the ones furthest from the player. Merged gems keep the total value.
+#[allow(clippy::type_complexity)]
+pub fn merge_experience(
+    mut commands: Commands,
+    mut experience_query: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Experience,
+            &mut ExperienceValue,
+            &mut Handle<TextureAtlas>,
+        ),
+        (Without<Magnetized>, Without<Player>),
+    >,
+    player_query: Query<&Transform, With<Player>>,
+    experience_atlases: Res<ExperienceAtlases>,
+    experience_merging: Res<ExperienceMerging>,
+) {
+    let mut clusters: HashMap<IVec2, Vec<Entity>> = HashMap::new();
+    for (entity, transform, ..) in &experience_query {
+        let cell = (transform.translation.truncate() / experience_merging.radius)
+            .floor()
+            .as_ivec2();
+        clusters.entry(cell).or_default().push(entity);
+    }
+
+    let mut merges: Vec<Vec<Entity>> = clusters
+        .into_values()
+        .filter(|cluster| cluster.len() > 1)
+        .collect();
+
+    let remaining = experience_query.iter().len() - merges.iter().map(|m| m.len() - 1).sum::<usize>();
+    if remaining > experience_merging.cap {
+        if let Ok(player_transform) = player_query.get_single() {
+            let merged: Vec<Entity> = merges.iter().flatten().copied().collect();
+            let mut by_distance: Vec<(Entity, f32)> = experience_query
+                .iter()
+                .filter(|(entity, ..)| !merged.contains(entity))
+                .map(|(entity, transform, ..)| {
+                    (entity, transform.translation.distance(player_transform.translation))
+                })
+                .collect();
+            by_distance.sort_by(|a, b| b.1.total_cmp(&a.1));
+            merges.push(
+                by_distance
+                    .into_iter()
+                    .take(remaining - experience_merging.cap + 1)
+                    .map(|(entity, _)| entity)
+                    .collect(),
+            );
+        }
+    }
+
+    for merge in merges {
+        let Some((&survivor, others)) = merge.split_first() else {
+            continue;
+        };
+
+        let mut total = 0.0;
+        for &entity in &merge {
+            if let Ok((_, _, _, value, _)) = experience_query.get(entity) {
+                total += value.0;
             }
         }
+        for &entity in others {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        if let Ok((_, _, mut experience, mut value, mut texture_atlas)) =
+            experience_query.get_mut(survivor)
+        {
+            value.0 = total;
+            *experience = Experience::for_value(total);
+            *texture_atlas = experience_atlases.get(*experience);
+        }
+    }
+}
+
+pub fn update_experience_labels(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    experience_query: Query<
+        (Entity, &Experience, &ExperienceValue, Option<&Children>),
+        Changed<ExperienceValue>,
+    >,
+    mut label_query: Query<&mut Text, With<ExperienceLabel>>,
+) {
+    for (entity, experience, value, children) in &experience_query {
+        if value.0 <= Into::<f32>::into(*experience) {
+            continue;
+        }
+
+        let text = format!("{}", value.0);
+        if let Some(mut label) = children
+            .and_then(|children| children.iter().find(|child| label_query.contains(**child)))
+            .and_then(|child| label_query.get_mut(*child).ok())
+        {
+            label.sections[0].value = text;
+            continue;
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            parent.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        text,
+                        TextStyle {
+                            font: asset_server.load("fonts/Davidfont.otf"),
+                            font_size: EXPERIENCE_LABEL_FONT_SIZE,
+                            color: Color::hsla(0.0, 0.0, 0.1, 1.0),
+                        },
+                    ),
+                    transform: Transform::from_xyz(0.0, 64.0, 1.0),
+                    ..default()
+                },
+                ExperienceLabel {},
+            ));
+        });
     }
 }
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+#[derive(Component)]
+pub struct HealthPickup {
+    pub amount: f32,
+}
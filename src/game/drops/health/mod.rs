@@ -0,0 +1,27 @@
+pub mod components;
+pub mod events;
+pub mod systems;
+
+use bevy::prelude::*;
+
+use crate::game::states::GameState;
+
+use self::{
+    events::SendHealthEvent,
+    systems::{player_collect, spawn_health},
+};
+
+pub const HEALTH_PICKUP_AMOUNT: f32 = 3.0;
+pub const HEALTH_PICKUP_COLOR: Color = Color::RED;
+
+pub struct HealthPlugin;
+
+impl Plugin for HealthPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SendHealthEvent>()
+            .add_systems(
+                Update,
+                (spawn_health, player_collect).run_if(in_state(GameState::Running)),
+            );
+    }
+}
@@ -0,0 +1,80 @@
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+
+use crate::game::{
+    drops::{components::Drop, events::DropSpawnEvent, tables::DropKind},
+    player::components::{PickupRadius, Player},
+};
+
+use super::{
+    components::HealthPickup, events::SendHealthEvent, HEALTH_PICKUP_AMOUNT, HEALTH_PICKUP_COLOR,
+};
+
+pub fn spawn_health(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut drop_spawn_event_reader: EventReader<DropSpawnEvent>,
+) {
+    for event in &mut drop_spawn_event_reader {
+        if event.kind == DropKind::Health {
+            spawn_health_pickup(&mut commands, &mut meshes, &mut materials, event.position);
+        }
+    }
+}
+
+/// Spawns a heart built from two circles on top of a diamond.
+pub fn spawn_health_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    position: Vec3,
+) -> Entity {
+    let material = materials.add(ColorMaterial::from(HEALTH_PICKUP_COLOR));
+    let lobe = meshes.add(shape::Circle::new(3.0).into());
+    let point = meshes.add(shape::Quad::new(Vec2::splat(6.0)).into());
+
+    commands
+        .spawn((
+            Drop {},
+            HealthPickup {
+                amount: HEALTH_PICKUP_AMOUNT,
+            },
+            Name::from("Health Pickup"),
+            SpatialBundle::from_transform(Transform::from_translation(position)),
+        ))
+        .with_children(|parent| {
+            for x in [-2.1, 2.1] {
+                parent.spawn(MaterialMesh2dBundle {
+                    mesh: lobe.clone().into(),
+                    material: material.clone(),
+                    transform: Transform::from_xyz(x, 1.5, 0.0),
+                    ..default()
+                });
+            }
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: point.clone().into(),
+                material: material.clone(),
+                transform: Transform::from_xyz(0.0, -0.6, 0.0)
+                    .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_4)),
+                ..default()
+            });
+        })
+        .id()
+}
+
+#[allow(clippy::type_complexity)]
+pub fn player_collect(
+    mut commands: Commands,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<HealthPickup>)>,
+    health_pickup_query: Query<(Entity, &HealthPickup, &Transform), Without<Player>>,
+    mut send_health_event_writer: EventWriter<SendHealthEvent>,
+) {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (health_pickup_entity, health_pickup, health_pickup_transform) in &health_pickup_query {
+            if pickup_radius.collects(player_transform.translation, health_pickup_transform.translation) {
+                commands.entity(health_pickup_entity).despawn_recursive();
+                send_health_event_writer.send(SendHealthEvent(health_pickup.amount));
+            }
+        }
+    }
+}
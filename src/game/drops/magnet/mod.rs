@@ -0,0 +1,22 @@
+pub mod components;
+pub mod systems;
+
+use bevy::prelude::*;
+
+use crate::game::states::GameState;
+
+use self::systems::{player_collect, spawn_magnet};
+
+pub const MAGNET_COLOR: Color = Color::rgb(0.8, 0.1, 0.1);
+pub const MAGNET_TIP_COLOR: Color = Color::GRAY;
+
+pub struct MagnetPlugin;
+
+impl Plugin for MagnetPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            (spawn_magnet, player_collect).run_if(in_state(GameState::Running)),
+        );
+    }
+}
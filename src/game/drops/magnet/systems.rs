@@ -0,0 +1,93 @@
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
+
+use crate::game::{
+    drops::{
+        components::{Drop, Magnetized},
+        events::DropSpawnEvent,
+        tables::DropKind,
+        MAGNETIZED_START_SPEED,
+    },
+    player::components::{PickupRadius, Player},
+};
+
+use super::{components::MagnetPickup, MAGNET_COLOR, MAGNET_TIP_COLOR};
+
+pub fn spawn_magnet(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut drop_spawn_event_reader: EventReader<DropSpawnEvent>,
+) {
+    for event in &mut drop_spawn_event_reader {
+        if event.kind == DropKind::Magnet {
+            spawn_magnet_pickup(&mut commands, &mut meshes, &mut materials, event.position);
+        }
+    }
+}
+
+/// Spawns a horseshoe magnet built from three bars with grey tips.
+pub fn spawn_magnet_pickup(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    position: Vec3,
+) -> Entity {
+    let material = materials.add(ColorMaterial::from(MAGNET_COLOR));
+    let tip_material = materials.add(ColorMaterial::from(MAGNET_TIP_COLOR));
+    let side = meshes.add(shape::Quad::new(Vec2::new(2.5, 8.0)).into());
+    let bottom = meshes.add(shape::Quad::new(Vec2::new(8.0, 2.5)).into());
+    let tip = meshes.add(shape::Quad::new(Vec2::new(2.5, 2.5)).into());
+
+    commands
+        .spawn((
+            Drop {},
+            MagnetPickup {},
+            Name::from("Magnet Pickup"),
+            SpatialBundle::from_transform(Transform::from_translation(position)),
+        ))
+        .with_children(|parent| {
+            for x in [-2.75, 2.75] {
+                parent.spawn(MaterialMesh2dBundle {
+                    mesh: side.clone().into(),
+                    material: material.clone(),
+                    transform: Transform::from_xyz(x, 0.0, 0.0),
+                    ..default()
+                });
+                parent.spawn(MaterialMesh2dBundle {
+                    mesh: tip.clone().into(),
+                    material: tip_material.clone(),
+                    transform: Transform::from_xyz(x, 5.25, 0.0),
+                    ..default()
+                });
+            }
+            parent.spawn(MaterialMesh2dBundle {
+                mesh: bottom.clone().into(),
+                material: material.clone(),
+                transform: Transform::from_xyz(0.0, -2.75, 0.0),
+                ..default()
+            });
+        })
+        .id()
+}
+
+/// Collecting a magnet pulls every other drop on the map to the player.
+#[allow(clippy::type_complexity)]
+pub fn player_collect(
+    mut commands: Commands,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<MagnetPickup>)>,
+    magnet_query: Query<(Entity, &Transform), (With<MagnetPickup>, Without<Player>)>,
+    drops_query: Query<Entity, (With<Drop>, Without<MagnetPickup>, Without<Magnetized>)>,
+) {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (magnet_entity, magnet_transform) in &magnet_query {
+            if pickup_radius.collects(player_transform.translation, magnet_transform.translation) {
+                commands.entity(magnet_entity).despawn_recursive();
+                for drop_entity in &drops_query {
+                    commands.entity(drop_entity).insert(Magnetized {
+                        speed: MAGNETIZED_START_SPEED,
+                    });
+                }
+            }
+        }
+    }
+}
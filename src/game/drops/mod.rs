@@ -1,20 +1,52 @@
+pub mod chest;
+pub mod coin;
+pub mod components;
 pub mod experience;
-mod components;
+pub mod events;
+pub mod health;
+pub mod magnet;
+pub mod resources;
 mod systems;
+pub mod tables;
 
 use bevy::prelude::*;
 
-use self::{experience::ExperiencePlugin, systems::move_drops};
+use self::{
+    chest::ChestPlugin,
+    coin::CoinPlugin,
+    events::DropSpawnEvent,
+    experience::ExperiencePlugin,
+    health::HealthPlugin,
+    magnet::MagnetPlugin,
+    systems::{cull_far_drops, despawn_drops, drop_on_enemy_death, load_drop_tables, move_drops},
+    tables::{DropTables, DropTablesLoader},
+};
+
+use crate::states::AppState;
 
 use super::states::GameState;
 
-pub const DROPS_DISTANCE_THRESHOLD: f32 = 200.0;
+/// XP gems and health pickups further than this from the camera are despawned to keep entity
+/// counts bounded.
+pub const DROPS_CULL_DISTANCE: f32 = 3200.0;
+/// Speed a magnetized drop starts at, and how much faster it gets every second, chosen so a
+/// drop at `DROPS_CULL_DISTANCE` arrives within two seconds.
+pub const MAGNETIZED_START_SPEED: f32 = 400.0;
+pub const MAGNETIZED_ACCELERATION: f32 = 1200.0;
 
 pub struct DropsPlugin;
 
 impl Plugin for DropsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ExperiencePlugin)
-            .add_systems(Update, move_drops.run_if(in_state(GameState::Running)));
+        app.add_asset::<DropTables>()
+            .init_asset_loader::<DropTablesLoader>()
+            .add_event::<DropSpawnEvent>()
+            .add_plugins((ChestPlugin, CoinPlugin, ExperiencePlugin, HealthPlugin, MagnetPlugin))
+            .add_systems(Startup, load_drop_tables)
+            .add_systems(
+                Update,
+                (drop_on_enemy_death, move_drops, cull_far_drops).run_if(in_state(GameState::Running)),
+            )
+            .add_systems(OnExit(AppState::Game), despawn_drops);
     }
 }
\ No newline at end of file
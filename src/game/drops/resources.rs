@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+use super::tables::DropTables;
+
+#[derive(Resource)]
+pub struct DropTablesHandle(pub Handle<DropTables>);
@@ -1,21 +1,93 @@
 use bevy::prelude::*;
 
-use crate::game::player::components::Player;
-use super::{components::Drop, DROPS_DISTANCE_THRESHOLD};
+use crate::game::{
+    enemies::events::EnemyDeathEvent,
+    player::components::{PickupRadius, Player},
+    resources::GameRng,
+};
+use super::{
+    components::{Drop, Magnetized},
+    events::DropSpawnEvent,
+    experience::components::Experience,
+    health::components::HealthPickup,
+    resources::DropTablesHandle,
+    tables::{DropTable, DropTables},
+    DROPS_CULL_DISTANCE, MAGNETIZED_ACCELERATION,
+};
 
+#[allow(clippy::type_complexity)]
 pub fn move_drops(
-    mut drops_query: Query<&mut Transform, (With<Drop>, Without<Player>)>,
-    player_query: Query<&Transform, (With<Player>, Without<Drop>)>,
+    mut drops_query: Query<(&mut Transform, Option<&mut Magnetized>), (With<Drop>, Without<Player>)>,
+    player_query: Query<(&Transform, &PickupRadius), (With<Player>, Without<Drop>)>,
     time: Res<Time>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        for mut transform in &mut drops_query {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        for (mut transform, magnetized) in &mut drops_query {
             let direction = player_transform.translation - transform.translation;
             let distance = player_transform.translation.distance(transform.translation);
 
-            if distance < DROPS_DISTANCE_THRESHOLD {
-                transform.translation += direction.normalize_or_zero() * (DROPS_DISTANCE_THRESHOLD - distance) * 2.0 * time.delta_seconds();
+            if let Some(mut magnetized) = magnetized {
+                magnetized.speed += MAGNETIZED_ACCELERATION * time.delta_seconds();
+                transform.translation += direction.normalize_or_zero()
+                    * (magnetized.speed * time.delta_seconds()).min(distance);
+            } else if distance < pickup_radius.0 {
+                transform.translation += direction.normalize_or_zero() * (pickup_radius.0 - distance) * 2.0 * time.delta_seconds();
             }
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn despawn_drops(mut commands: Commands, drops_query: Query<Entity, With<Drop>>) {
+    for entity in &drops_query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Culls XP gems and health pickups left far behind. Other drops are kept however far away they
+/// are, as they're worth coming back for.
+#[allow(clippy::type_complexity)]
+pub fn cull_far_drops(
+    mut commands: Commands,
+    drops_query: Query<(Entity, &Transform), (Or<(With<Experience>, With<HealthPickup>)>, Without<Camera>)>,
+    camera_query: Query<&Transform, With<Camera>>,
+) {
+    if let Ok(camera_transform) = camera_query.get_single() {
+        for (entity, transform) in &drops_query {
+            if camera_transform.translation.truncate().distance(transform.translation.truncate())
+                > DROPS_CULL_DISTANCE
+            {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    }
+}
+
+pub fn load_drop_tables(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(DropTablesHandle(
+        asset_server.load("drops/enemies.drops.ron"),
+    ));
+}
+
+pub fn drop_on_enemy_death(
+    mut enemy_death_event_reader: EventReader<EnemyDeathEvent>,
+    mut drop_spawn_event_writer: EventWriter<DropSpawnEvent>,
+    drop_tables_handle: Res<DropTablesHandle>,
+    drop_tables: Res<Assets<DropTables>>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let default_table = DropTable::default();
+
+    for event in &mut enemy_death_event_reader {
+        let table = drop_tables
+            .get(&drop_tables_handle.0)
+            .and_then(|drop_tables| drop_tables.get(event.kind, event.elite, event.boss))
+            .unwrap_or(&default_table);
+
+        for kind in table.roll(&mut game_rng.rng) {
+            drop_spawn_event_writer.send(DropSpawnEvent {
+                kind,
+                position: event.position,
+            });
+        }
+    }
+}
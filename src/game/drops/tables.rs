@@ -0,0 +1,122 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use rand::{distributions::WeightedIndex, prelude::Distribution, Rng};
+use serde::{Deserialize, Serialize};
+
+use crate::game::enemies::components::EnemyKind;
+
+use super::experience::components::Experience;
+
+/// Everything an enemy can drop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DropKind {
+    Experience(Experience),
+    Health,
+    Magnet,
+    Chest,
+    Coin,
+}
+
+/// One weighted outcome of a drop roll. `None` means nothing drops.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DropEntry {
+    pub weight: u32,
+    pub drop: Option<DropKind>,
+}
+
+/// What a particular kind of enemy drops when it dies.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DropTable {
+    pub enemy: EnemyKind,
+    #[serde(default)]
+    pub elite: bool,
+    #[serde(default)]
+    pub boss: bool,
+    pub entries: Vec<DropEntry>,
+    /// Dropped every time, on top of the weighted roll.
+    #[serde(default)]
+    pub guaranteed: Vec<DropKind>,
+}
+
+impl DropTable {
+    /// The drops for a single death: every guaranteed drop plus one weighted pick.
+    pub fn roll(&self, rng: &mut impl Rng) -> Vec<DropKind> {
+        let mut drops = self.guaranteed.clone();
+        if let Ok(index) = WeightedIndex::new(self.entries.iter().map(|entry| entry.weight)) {
+            drops.extend(self.entries[index.sample(rng)].drop);
+        }
+        drops
+    }
+
+    fn matches(&self, enemy: EnemyKind, elite: bool, boss: bool) -> bool {
+        self.enemy == enemy && self.elite == elite && self.boss == boss
+    }
+}
+
+impl Default for DropTable {
+    /// Used when no table matches or the asset hasn't loaded: the original experience odds.
+    fn default() -> Self {
+        DropTable {
+            enemy: EnemyKind::Triangle,
+            elite: false,
+            boss: false,
+            entries: vec![
+                DropEntry {
+                    weight: 9900,
+                    drop: Some(DropKind::Experience(Experience::X)),
+                },
+                DropEntry {
+                    weight: 99,
+                    drop: Some(DropKind::Experience(Experience::Y)),
+                },
+                DropEntry {
+                    weight: 1,
+                    drop: Some(DropKind::Experience(Experience::Z)),
+                },
+            ],
+            guaranteed: Vec::new(),
+        }
+    }
+}
+
+/// All drop tables, loaded from a `.drops.ron` asset.
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "b2f3c0e4-6d1a-4b8e-9f27-0c5d8a7e14f6"]
+pub struct DropTables {
+    pub tables: Vec<DropTable>,
+}
+
+impl DropTables {
+    /// Finds the table for an exact enemy kind and rank, falling back to the kind's regular
+    /// table.
+    pub fn get(&self, enemy: EnemyKind, elite: bool, boss: bool) -> Option<&DropTable> {
+        self.tables
+            .iter()
+            .find(|table| table.matches(enemy, elite, boss))
+            .or_else(|| self.tables.iter().find(|table| table.matches(enemy, false, false)))
+    }
+}
+
+#[derive(Default)]
+pub struct DropTablesLoader;
+
+impl AssetLoader for DropTablesLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let drop_tables = ron::de::from_bytes::<DropTables>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(drop_tables));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["drops.ron"]
+    }
+}
@@ -1,9 +1,52 @@
+use std::fmt::Display;
+
 use bevy::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
-#[derive(Component)]
+use crate::game::player::abilities::components::DamageSource;
+
+/// The different types of enemy, used to tell them apart in events and stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum EnemyKind {
+    Triangle,
+}
+
+impl Display for EnemyKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EnemyKind::Triangle => write!(f, "Triangle"),
+        }
+    }
+}
+
+/// Damage dealt to the player by touching them, and how close counts as touching.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct ContactDamage {
+    pub damage: f32,
+    pub range: f32,
+}
+
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Enemy {
     pub targetable: bool,
+    pub kind: EnemyKind,
+    pub elite: bool,
+    pub boss: bool,
+    /// The source of the most recent hit, which is credited with the kill.
+    pub last_damage_source: Option<DamageSource>,
+}
+
+impl Enemy {
+    pub fn new(kind: EnemyKind) -> Self {
+        Enemy {
+            targetable: false,
+            kind,
+            elite: false,
+            boss: false,
+            last_damage_source: None,
+        }
+    }
 }
 
 #[derive(Component)]
@@ -46,7 +89,7 @@ impl HordeMover {
     ///
     /// fn setup_horde_entity(mut commands: Commands) {
     ///     let mut mover = HordeMover::default();
-    ///     mover.noise(); // Introduce random noise to the movement direction
+    ///     mover.noise(&mut rand::thread_rng()); // Introduce random noise to the movement direction
     ///
     ///     commands.spawn().insert(mover);
     /// }
@@ -64,8 +107,14 @@ impl HordeMover {
     /// Be cautious when using high noise values, as they can result in erratic and unpredictable movement patterns.
     /// Adjust the noise range as needed to achieve the desired balance between randomness and controlled movement.
     ///
-    pub fn noise(&mut self) {
-        self.dxdy.x = rand::thread_rng().gen_range(-75.0..75.0);
-        self.dxdy.y = rand::thread_rng().gen_range(-75.0..75.0);
+    pub fn noise(&mut self, rng: &mut impl Rng) {
+        self.dxdy.x = rng.gen_range(-75.0..75.0);
+        self.dxdy.y = rng.gen_range(-75.0..75.0);
     }
 }
+
+/// A number floating up from where an enemy was hit, showing how hard.
+#[derive(Component)]
+pub struct DamageNumber {
+    pub timer: Timer,
+}
@@ -1,4 +1,17 @@
-use bevy::prelude::Event;
+use bevy::prelude::{Entity, Event, Vec3};
 
+use crate::game::player::abilities::components::DamageSource;
+
+use super::components::EnemyKind;
+
+/// Sent once for every enemy that dies, just before it is despawned.
 #[derive(Event)]
-pub struct EnemyDeathEvent;
+pub struct EnemyDeathEvent {
+    /// The dead enemy, which has already been queued for despawning.
+    pub entity: Entity,
+    pub kind: EnemyKind,
+    pub position: Vec3,
+    pub source: Option<DamageSource>,
+    pub elite: bool,
+    pub boss: bool,
+}
@@ -1,33 +1,56 @@
 pub mod components;
 pub mod events;
+pub mod resources;
 mod systems;
-mod triangle;
+pub mod triangle;
+
+use std::f32::consts::PI;
 
 use bevy::prelude::*;
 
 use self::{
     events::EnemyDeathEvent,
-    systems::{check_health, damage_enemies, update_enemy_targetable},
+    resources::EnemyCulling,
+    systems::{
+        animate_damage_numbers, check_health, damage_enemies, damage_numbers_enabled,
+        despawn_damage_numbers, reposition_far_enemies, spawn_damage_numbers,
+        update_enemy_targetable,
+    },
     triangle::TrianglePlugin,
 };
 
+use crate::states::AppState;
+
 use super::states::GameState;
 
 pub const ENEMY_STD_SPEED: f32 = 200.0;
 pub const ENEMY_STD_AVOIDANCE: f32 = 0.3;
 pub const ENEMY_STD_SIZE: f32 = 32.0;
+pub const ENEMY_CULL_DISTANCE: f32 = 1600.0;
+/// How far either side of the player's heading repositioned enemies may be placed, in radians.
+pub const ENEMY_REPOSITION_SPREAD: f32 = PI / 4.0;
+pub const DAMAGE_NUMBER_DURATION: f32 = 0.6;
+pub const DAMAGE_NUMBER_RISE_SPEED: f32 = 60.0;
+pub const DAMAGE_NUMBER_FONT_SIZE: f32 = 28.0;
+pub const DAMAGE_NUMBER_COLOR: Color = Color::WHITE;
 
 pub struct EnemiesPlugin;
 
 impl Plugin for EnemiesPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<EnemyDeathEvent>()
+            .init_resource::<EnemyCulling>()
             .add_plugins(TrianglePlugin)
             .add_systems(
                 Update,
-                (damage_enemies, check_health, update_enemy_targetable)
-                    .chain()
+                (
+                    (damage_enemies, check_health, update_enemy_targetable).chain(),
+                    reposition_far_enemies,
+                    spawn_damage_numbers.run_if(damage_numbers_enabled),
+                    animate_damage_numbers,
+                )
                     .run_if(in_state(GameState::Running)),
-            );
+            )
+            .add_systems(OnExit(AppState::Game), despawn_damage_numbers);
     }
 }
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+
+use super::ENEMY_CULL_DISTANCE;
+
+/// Controls when enemies that have fallen behind the player are brought back into play.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct EnemyCulling {
+    /// Enemies further than this from the camera are moved to the spawn ring.
+    pub distance: f32,
+}
+
+impl Default for EnemyCulling {
+    fn default() -> Self {
+        EnemyCulling {
+            distance: ENEMY_CULL_DISTANCE,
+        }
+    }
+}
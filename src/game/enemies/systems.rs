@@ -1,48 +1,178 @@
-use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use rand::Rng;
 
 use crate::game::{
-    components::Health, drops::experience::events::ExperienceSpawnEvent,
-    player::abilities::events::TransmitDamage,
-    grid::{GRID_WIDTH, GRID_HEIGHT},
+    components::Health,
+    player::{abilities::events::TransmitDamage, components::Player},
+    grid::resources::GridSettings,
+    resources::GameRng,
 };
+use crate::settings::resources::GameSettings;
 
-use super::components::Enemy;
+use super::{
+    components::{DamageNumber, Enemy, HordeMover},
+    events::EnemyDeathEvent,
+    resources::EnemyCulling,
+    DAMAGE_NUMBER_COLOR, DAMAGE_NUMBER_DURATION, DAMAGE_NUMBER_FONT_SIZE, DAMAGE_NUMBER_RISE_SPEED,
+    ENEMY_REPOSITION_SPREAD,
+};
 
 pub fn check_health(
     mut commands: Commands,
-    enemies_query: Query<(Entity, &Health, &Transform)>,
-    mut experience_spawn_event_writer: EventWriter<ExperienceSpawnEvent>,
+    enemies_query: Query<(Entity, &Enemy, &Health, &Transform)>,
+    mut enemy_death_event_writer: EventWriter<EnemyDeathEvent>,
 ) {
-    for (entity, health, transform) in &enemies_query {
+    for (entity, enemy, health, transform) in &enemies_query {
         if health.0 <= 0.0 {
             commands.entity(entity).despawn_recursive();
-            experience_spawn_event_writer.send(ExperienceSpawnEvent(*transform))
+            enemy_death_event_writer.send(EnemyDeathEvent {
+                entity,
+                kind: enemy.kind,
+                position: transform.translation,
+                source: enemy.last_damage_source,
+                elite: enemy.elite,
+                boss: enemy.boss,
+            });
         }
     }
 }
 
 pub fn damage_enemies(
     mut transmit_damage_event_reader: EventReader<TransmitDamage>,
-    mut enemies_query: Query<&mut Health, With<Enemy>>,
+    mut enemies_query: Query<(&mut Health, &mut Enemy)>,
 ) {
     for event in &mut transmit_damage_event_reader {
-        if let Ok(mut health) = enemies_query.get_mut(event.target) {
+        if let Ok((mut health, mut enemy)) = enemies_query.get_mut(event.target) {
             health.0 -= event.damage;
+            enemy.last_damage_source = Some(event.source);
+        }
+    }
+}
+
+pub fn spawn_damage_numbers(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut transmit_damage_event_reader: EventReader<TransmitDamage>,
+    enemies_query: Query<&Transform, With<Enemy>>,
+) {
+    for event in &mut transmit_damage_event_reader {
+        if let Ok(transform) = enemies_query.get(event.target) {
+            commands.spawn((
+                Text2dBundle {
+                    text: Text::from_section(
+                        event.damage.round().to_string(),
+                        TextStyle {
+                            font: asset_server.load("fonts/Davidfont.otf"),
+                            font_size: DAMAGE_NUMBER_FONT_SIZE,
+                            color: DAMAGE_NUMBER_COLOR,
+                        },
+                    ),
+                    transform: Transform::from_translation(transform.translation.truncate().extend(200.0)),
+                    ..default()
+                },
+                DamageNumber {
+                    timer: Timer::from_seconds(DAMAGE_NUMBER_DURATION, TimerMode::Once),
+                },
+                Name::from("Damage Number"),
+            ));
         }
     }
 }
 
+/// Floats damage numbers upwards, fading them out until they're gone.
+pub fn animate_damage_numbers(
+    mut commands: Commands,
+    mut damage_numbers_query: Query<(Entity, &mut DamageNumber, &mut Transform, &mut Text)>,
+    time: Res<Time>,
+) {
+    for (entity, mut damage_number, mut transform, mut text) in &mut damage_numbers_query {
+        if damage_number.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            continue;
+        }
+        transform.translation.y += DAMAGE_NUMBER_RISE_SPEED * time.delta_seconds();
+        text.sections[0]
+            .style
+            .color
+            .set_a(damage_number.timer.percent_left());
+    }
+}
+
+pub fn despawn_damage_numbers(
+    mut commands: Commands,
+    damage_numbers_query: Query<Entity, With<DamageNumber>>,
+) {
+    for entity in &damage_numbers_query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+pub fn damage_numbers_enabled(game_settings: Res<GameSettings>) -> bool {
+    game_settings.damage_numbers
+}
+
 pub fn update_enemy_targetable(
-    mut enemies_query: Query<(&Transform, &mut Enemy)>,
+    mut enemies_query: Query<(&Transform, &mut Enemy), Without<Camera>>,
+    camera_query: Query<&Transform, With<Camera>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    grid_settings: Res<GridSettings>,
 ) {
-    for (transform, mut enemy) in &mut enemies_query {
-        let x = transform.translation.x;
-        let y = transform.translation.y;
+    if let (Ok(camera_transform), Ok(window)) = (camera_query.get_single(), window_query.get_single()) {
+        let play_area = grid_settings.play_area(
+            camera_transform.translation.truncate(),
+            Vec2::new(window.width(), window.height()),
+        );
 
-        if x >= 0.0 && x <= GRID_WIDTH as f32 && y >= 0.0 && y <= GRID_HEIGHT as f32 {
-            enemy.targetable = true;
+        for (transform, mut enemy) in &mut enemies_query {
+            enemy.targetable = play_area.contains(transform.translation.truncate());
+        }
+    }
+}
+
+/// Moves enemies that have fallen too far behind back onto the spawn ring, ahead of where the
+/// player is heading, so they keep pressuring the player instead of trailing forever.
+#[allow(clippy::type_complexity)]
+pub fn reposition_far_enemies(
+    mut enemies_query: Query<(&mut Transform, &mut HordeMover), (With<Enemy>, Without<Camera>)>,
+    camera_query: Query<&Transform, With<Camera>>,
+    player_query: Query<&Player>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    enemy_culling: Res<EnemyCulling>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    if let (Ok(camera_transform), Ok(player), Ok(window)) = (
+        camera_query.get_single(),
+        player_query.get_single(),
+        window_query.get_single(),
+    ) {
+        let rng = &mut game_rng.rng;
+        let heading = if player.direction == Vec3::ZERO {
+            None
         } else {
-            enemy.targetable = false;
+            Some(player.direction.y.atan2(player.direction.x))
+        };
+
+        for (mut transform, mut hordemover) in &mut enemies_query {
+            let distance = camera_transform
+                .translation
+                .truncate()
+                .distance(transform.translation.truncate());
+            if distance <= enemy_culling.distance {
+                continue;
+            }
+
+            let angle = match heading {
+                Some(heading) => {
+                    heading + rng.gen_range(-ENEMY_REPOSITION_SPREAD..ENEMY_REPOSITION_SPREAD)
+                }
+                None => rng.gen_range(0.0..TAU),
+            };
+            let (y, x) = angle.sin_cos();
+            transform.translation.x = camera_transform.translation.x + x * window.width() / 2.0;
+            transform.translation.y = camera_transform.translation.y + y * window.width() / 2.0;
+            hordemover.dxdy = Vec3::ZERO;
         }
     }
 }
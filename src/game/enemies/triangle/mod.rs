@@ -1,5 +1,5 @@
-mod components;
-mod systems;
+pub mod components;
+pub mod systems;
 
 use bevy::prelude::*;
 
@@ -10,6 +10,11 @@ use self::systems::{
     spawn_triangles,
 };
 
+pub const TRIANGLE_HEALTH: f32 = 100.0;
+pub const TRIANGLE_CONTACT_DAMAGE: f32 = 2.0;
+/// Contact range at the regular triangle scale of 0.5; bigger triangles reach further.
+pub const TRIANGLE_CONTACT_RANGE: f32 = 32.0;
+
 pub struct TrianglePlugin;
 
 impl Plugin for TrianglePlugin {
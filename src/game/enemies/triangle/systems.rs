@@ -5,13 +5,16 @@ use bevy::{prelude::*, window::PrimaryWindow};
 use crate::game::{
     components::{AnimationIndices, AnimationTimer, Health},
     enemies::{
-        components::{Enemy, HordeMover},
+        components::{ContactDamage, Enemy, EnemyKind, HordeMover},
         ENEMY_STD_AVOIDANCE, ENEMY_STD_SIZE, ENEMY_STD_SPEED,
     },
     player::components::Player,
+    resources::GameRng,
 };
 
-use super::components::Triangle;
+use super::{
+    components::Triangle, TRIANGLE_CONTACT_DAMAGE, TRIANGLE_CONTACT_RANGE, TRIANGLE_HEALTH,
+};
 use rand::Rng;
 
 pub fn spawn_triangles(
@@ -21,53 +24,90 @@ pub fn spawn_triangles(
     player_query: Query<&Transform, (With<Player>, Without<Enemy>)>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     enemy_query: Query<Entity, With<Enemy>>,
+    mut game_rng: ResMut<GameRng>,
 ) {
     if enemy_query.iter().count() > 5 {
         return;
     }
 
+    let texture_atlas_handle = load_triangle_texture_atlas(&asset_server, &mut texture_atlases);
+
+    if let Ok(window) = window_query.get_single() {
+        if let Ok(player_transform) = player_query.get_single() {
+            let rng = &mut game_rng.rng;
+            for _ in 0..10 {
+                let position = spawn_ring_position(player_transform.translation, window, rng);
+                let scale = triangle_scale(rng);
+                spawn_triangle(&mut commands, texture_atlas_handle.clone(), position, scale);
+            }
+        }
+    }
+}
+
+pub fn load_triangle_texture_atlas(
+    asset_server: &Res<AssetServer>,
+    texture_atlases: &mut ResMut<Assets<TextureAtlas>>,
+) -> Handle<TextureAtlas> {
     let texture_handle = asset_server.load("sprites/triangle_enemy_4_frame_64x64.png");
     let texture_atlas =
         TextureAtlas::from_grid(texture_handle, Vec2::new(64.0, 64.0), 4, 1, None, None);
-    let texture_atlas_handle = texture_atlases.add(texture_atlas);
+    texture_atlases.add(texture_atlas)
+}
+
+/// A random point on the ring just outside the screen around `center`.
+fn spawn_ring_position(center: Vec3, window: &Window, rng: &mut impl Rng) -> Vec3 {
+    let angle = rng.gen::<f32>() * PI * 2.0;
+    let (y, x) = angle.sin_cos();
+    Vec3::new(
+        center.x + x * window.width() / 2.0,
+        center.y + y * window.width() / 2.0,
+        100.0,
+    )
+}
+
+/// Triangles vary a little in size.
+fn triangle_scale(rng: &mut impl Rng) -> Vec3 {
+    Vec3 {
+        x: 0.5 * rng.gen_range(0.75..1.0),
+        y: 0.5 * rng.gen_range(0.75..1.0),
+        z: 1.0,
+    }
+}
+
+/// Spawns a triangle at full health, returning it so a resumed run can restore its state.
+pub fn spawn_triangle(
+    commands: &mut Commands,
+    texture_atlas_handle: Handle<TextureAtlas>,
+    translation: Vec3,
+    scale: Vec3,
+) -> Entity {
     let animation_indices = AnimationIndices {
         first: 0,
         last: 3,
         reverse: false,
     };
 
-    if let Ok(window) = window_query.get_single() {
-        if let Ok(player_transform) = player_query.get_single() {
-            for _ in 0..10 {
-                let angle = rand::random::<f32>() * PI * 2.0;
-                let (y, x) = angle.sin_cos();
-                commands.spawn((
-                    Triangle {},
-                    Name::from("Triangle"),
-                    Enemy { targetable: false },
-                    Health(100.0),
-                    HordeMover::default(),
-                    SpriteSheetBundle {
-                        texture_atlas: texture_atlas_handle.clone(),
-                        sprite: TextureAtlasSprite::new(animation_indices.clone().first),
-                        transform: Transform::from_xyz(
-                            player_transform.translation.x + x * window.width() / 2.0,
-                            player_transform.translation.y + y * window.width() / 2.0,
-                            100.0,
-                        )
-                        .with_scale(Vec3 {
-                            x: 0.5 * rand::thread_rng().gen_range(0.75..1.0),
-                            y: 0.5 * rand::thread_rng().gen_range(0.75..1.0),
-                            z: 1.0,
-                        }),
-                        ..default()
-                    },
-                    animation_indices,
-                    AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
-                ));
-            }
-        }
-    }
+    commands
+        .spawn((
+            Triangle {},
+            Name::from("Triangle"),
+            Enemy::new(EnemyKind::Triangle),
+            Health(TRIANGLE_HEALTH),
+            ContactDamage {
+                damage: TRIANGLE_CONTACT_DAMAGE,
+                range: TRIANGLE_CONTACT_RANGE * scale.x / 0.5,
+            },
+            HordeMover::default(),
+            SpriteSheetBundle {
+                texture_atlas: texture_atlas_handle,
+                sprite: TextureAtlasSprite::new(animation_indices.first),
+                transform: Transform::from_translation(translation).with_scale(scale),
+                ..default()
+            },
+            animation_indices,
+            AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+        ))
+        .id()
 }
 
 pub fn despawn_triangles(player_query: Query<Entity, With<Triangle>>, mut commands: Commands) {
@@ -107,11 +147,12 @@ pub fn avoid_other_triangles(
 
 pub fn move_triangle(
     mut triangle_query: Query<(&mut Transform, &mut HordeMover), (With<Triangle>, Without<Player>)>,
+    mut game_rng: ResMut<GameRng>,
     time: Res<Time>,
 ) {
     for (mut transform, mut hordemover) in &mut triangle_query {
         transform.translation -=
             hordemover.dxdy.normalize_or_zero() * time.delta_seconds() * ENEMY_STD_SPEED;
-        hordemover.noise();
+        hordemover.noise(&mut game_rng.rng);
     }
 }
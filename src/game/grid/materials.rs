@@ -0,0 +1,35 @@
+use bevy::{
+    prelude::*,
+    reflect::{TypePath, TypeUuid},
+    render::render_resource::{AsBindGroup, ShaderRef},
+    sprite::Material2d,
+};
+
+/// Draws the whole background grid on a single quad.
+///
+/// Lines are computed per fragment from world coordinates, so the quad can be any size and
+/// can move without the grid itself appearing to move.
+#[derive(AsBindGroup, TypeUuid, TypePath, Debug, Clone)]
+#[uuid = "3f6a1c52-8a7e-4d6b-9c1e-52d1f0a7b3c4"]
+pub struct GridMaterial {
+    #[uniform(0)]
+    pub background_color: Color,
+    #[uniform(0)]
+    pub small_line_color: Color,
+    #[uniform(0)]
+    pub large_line_color: Color,
+    #[uniform(0)]
+    pub small_box_length: f32,
+    #[uniform(0)]
+    pub large_box_length: f32,
+    #[uniform(0)]
+    pub small_line_width: f32,
+    #[uniform(0)]
+    pub large_line_width: f32,
+}
+
+impl Material2d for GridMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "shaders/grid.wgsl".into()
+    }
+}
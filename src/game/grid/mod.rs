@@ -1,28 +1,40 @@
 pub mod components;
+mod materials;
+pub mod resources;
 mod systems;
 
-use bevy::prelude::*;
+use bevy::{prelude::*, sprite::Material2dPlugin};
 
 use crate::states::AppState;
 
-use self::systems::{despawn_grid, spawn_grid};
+use self::{
+    materials::GridMaterial,
+    resources::GridSettings,
+    systems::{despawn_grid, follow_camera, spawn_grid, unbounded_world},
+};
 
 pub const GRID_SMALL_BOX_LENGTH: u32 = 32;
 pub const GRID_LARGE_BOX_LENGTH: u32 = GRID_SMALL_BOX_LENGTH * 5;
 
 pub const NUM_SMALL_BOX_WIDTH: u32 = 850;
 pub const NUM_SMALL_BOX_HEIGHT: u32 = 1100;
-pub const NUM_LARGE_BOX_WIDTH: u32 = NUM_SMALL_BOX_WIDTH / 5;
-pub const NUM_LARGE_BOX_HEIGHT: u32 = NUM_SMALL_BOX_HEIGHT / 5;
 
-pub const GRID_WIDTH: u32 = GRID_SMALL_BOX_LENGTH * NUM_SMALL_BOX_WIDTH;
-pub const GRID_HEIGHT: u32 = GRID_SMALL_BOX_LENGTH * NUM_SMALL_BOX_HEIGHT;
+/// Side length of the background quad when it follows the camera instead of covering the grid.
+pub const UNBOUNDED_GRID_LENGTH: f32 = 8192.0;
 
 pub struct GridPlugin;
 
 impl Plugin for GridPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(AppState::Game), spawn_grid)
+        app.add_plugins(Material2dPlugin::<GridMaterial>::default())
+            .init_resource::<GridSettings>()
+            .add_systems(OnEnter(AppState::Game), spawn_grid)
+            .add_systems(
+                Update,
+                follow_camera
+                    .run_if(in_state(AppState::Game))
+                    .run_if(unbounded_world),
+            )
             .add_systems(OnExit(AppState::Game), despawn_grid);
     }
 }
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    GRID_LARGE_BOX_LENGTH, GRID_SMALL_BOX_LENGTH, NUM_SMALL_BOX_HEIGHT, NUM_SMALL_BOX_WIDTH,
+};
+
+/// How the edges of the play area behave.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum WorldMode {
+    /// The player is clamped to the grid and projectiles are culled at its edges.
+    #[default]
+    Bounded,
+    /// There are no edges; the background, spawns and culling follow the camera.
+    Infinite,
+    /// Leaving one edge of the grid puts you on the opposite edge.
+    Toroidal,
+}
+
+impl WorldMode {
+    /// The mode after this one, for cycling through them in menus.
+    pub fn next(self) -> Self {
+        match self {
+            WorldMode::Bounded => WorldMode::Infinite,
+            WorldMode::Infinite => WorldMode::Toroidal,
+            WorldMode::Toroidal => WorldMode::Bounded,
+        }
+    }
+}
+
+impl Display for WorldMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorldMode::Bounded => write!(f, "Bounded"),
+            WorldMode::Infinite => write!(f, "Infinite"),
+            WorldMode::Toroidal => write!(f, "Wrap"),
+        }
+    }
+}
+
+/// Size and look of the play area.
+///
+/// Defaults to the `GRID_*` constants; insert a different value before entering
+/// `AppState::Game` to change the world size.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct GridSettings {
+    pub small_box_length: f32,
+    pub large_box_length: f32,
+    pub num_small_box_width: u32,
+    pub num_small_box_height: u32,
+    pub background_color: Color,
+    pub small_line_color: Color,
+    pub large_line_color: Color,
+    pub small_line_width: f32,
+    pub large_line_width: f32,
+    pub mode: WorldMode,
+}
+
+impl Default for GridSettings {
+    fn default() -> Self {
+        GridSettings {
+            small_box_length: GRID_SMALL_BOX_LENGTH as f32,
+            large_box_length: GRID_LARGE_BOX_LENGTH as f32,
+            num_small_box_width: NUM_SMALL_BOX_WIDTH,
+            num_small_box_height: NUM_SMALL_BOX_HEIGHT,
+            background_color: Color::hsla(1.0, 1.0, 1.0, 1.0),
+            small_line_color: Color::hsla(191.0, 0.86, 0.42, 0.5),
+            large_line_color: Color::hsla(1.0, 0.86, 0.42, 0.5),
+            small_line_width: 1.0,
+            large_line_width: 1.5,
+            mode: WorldMode::default(),
+        }
+    }
+}
+
+impl GridSettings {
+    pub fn width(&self) -> f32 {
+        self.small_box_length * self.num_small_box_width as f32
+    }
+
+    pub fn height(&self) -> f32 {
+        self.small_box_length * self.num_small_box_height as f32
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.width(), self.height())
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.size() / 2.0
+    }
+
+    /// The area projectiles and targeting are limited to: the grid itself when bounded,
+    /// otherwise whatever the camera can currently see.
+    pub fn play_area(&self, camera_position: Vec2, view_size: Vec2) -> Rect {
+        match self.mode {
+            WorldMode::Bounded => Rect::from_corners(Vec2::ZERO, self.size()),
+            WorldMode::Infinite | WorldMode::Toroidal => {
+                Rect::from_center_size(camera_position, view_size)
+            }
+        }
+    }
+
+    /// Wraps `position` back onto the grid when the world is toroidal.
+    pub fn wrap(&self, position: Vec3) -> Vec3 {
+        match self.mode {
+            WorldMode::Toroidal => Vec3::new(
+                position.x.rem_euclid(self.width()),
+                position.y.rem_euclid(self.height()),
+                position.z,
+            ),
+            _ => position,
+        }
+    }
+
+    /// The displacement from `from` to `to`, taking the shortest way around a toroidal world.
+    pub fn offset(&self, from: Vec3, to: Vec3) -> Vec3 {
+        let mut offset = to - from;
+        if self.mode == WorldMode::Toroidal {
+            offset.x -= (offset.x / self.width()).round() * self.width();
+            offset.y -= (offset.y / self.height()).round() * self.height();
+        }
+        offset
+    }
+}
@@ -1,109 +1,43 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, sprite::MaterialMesh2dBundle};
 
 use super::{
-    components::Grid, GRID_HEIGHT, GRID_LARGE_BOX_LENGTH, GRID_SMALL_BOX_LENGTH, GRID_WIDTH,
-    NUM_LARGE_BOX_HEIGHT, NUM_LARGE_BOX_WIDTH, NUM_SMALL_BOX_HEIGHT, NUM_SMALL_BOX_WIDTH,
+    components::Grid,
+    materials::GridMaterial,
+    resources::{GridSettings, WorldMode},
+    UNBOUNDED_GRID_LENGTH,
 };
 
+pub fn spawn_grid(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<GridMaterial>>,
+    grid_settings: Res<GridSettings>,
+) {
+    let size = match grid_settings.mode {
+        WorldMode::Bounded => grid_settings.size(),
+        WorldMode::Infinite | WorldMode::Toroidal => Vec2::splat(UNBOUNDED_GRID_LENGTH),
+    };
 
-pub fn spawn_grid(mut commands: Commands) {
-    commands
-        .spawn((
-            SpriteBundle {
-                transform: Transform::from_translation(Vec3::new(
-                    GRID_WIDTH as f32 / 2.0,
-                    GRID_HEIGHT as f32 / 2.0,
-                    0.0,
-                )),
-                sprite: Sprite {
-                    color: Color::hsla(1.0, 1.0, 1.0, 1.0),
-                    custom_size: Some(Vec2::new(GRID_WIDTH as f32, GRID_HEIGHT as f32)),
-                    ..default()
-                },
-                visibility: Visibility::Visible,
-                ..default()
-            },
-            Grid {},
-            Name::from("Grid"),
-        ))
-        .with_children(|parent| {
-            for i in 0..=NUM_SMALL_BOX_WIDTH {
-                parent.spawn(SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        (i as f32 - NUM_SMALL_BOX_WIDTH as f32 / 2.0)
-                            * GRID_SMALL_BOX_LENGTH as f32,
-                        0.,
-                        1.0,
-                    )),
-                    sprite: Sprite {
-                        color: Color::hsla(191.0, 0.86, 0.42, 0.5),
-                        custom_size: Some(Vec2::new(
-                            1.0,
-                            NUM_SMALL_BOX_HEIGHT as f32 * GRID_SMALL_BOX_LENGTH as f32,
-                        )),
-                        ..default()
-                    },
-                    ..default()
-                });
-            }
-            for i in 0..=NUM_SMALL_BOX_HEIGHT {
-                parent.spawn(SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        0.,
-                        (i as f32 - NUM_SMALL_BOX_HEIGHT as f32 / 2.0)
-                            * GRID_SMALL_BOX_LENGTH as f32,
-                        1.0,
-                    )),
-                    sprite: Sprite {
-                        color: Color::hsla(191.0, 0.86, 0.42, 0.5),
-                        custom_size: Some(Vec2::new(
-                            NUM_SMALL_BOX_WIDTH as f32 * GRID_SMALL_BOX_LENGTH as f32,
-                            1.0,
-                        )),
-                        ..default()
-                    },
-                    ..default()
-                });
-            }
-            for i in 0..=NUM_LARGE_BOX_WIDTH {
-                parent.spawn(SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        (i as f32 - NUM_LARGE_BOX_WIDTH as f32 / 2.0)
-                            * GRID_LARGE_BOX_LENGTH as f32,
-                        0.,
-                        1.0,
-                    )),
-                    sprite: Sprite {
-                        color: Color::hsla(1.0, 0.86, 0.42, 0.5),
-                        custom_size: Some(Vec2::new(
-                            1.5,
-                            NUM_LARGE_BOX_HEIGHT as f32 * GRID_LARGE_BOX_LENGTH as f32,
-                        )),
-                        ..default()
-                    },
-                    ..default()
-                });
-            }
-            for i in 0..=NUM_LARGE_BOX_HEIGHT {
-                parent.spawn(SpriteBundle {
-                    transform: Transform::from_translation(Vec3::new(
-                        0.,
-                        (i as f32 - NUM_LARGE_BOX_HEIGHT as f32 / 2.0)
-                            * GRID_LARGE_BOX_LENGTH as f32,
-                        1.0,
-                    )),
-                    sprite: Sprite {
-                        color: Color::hsla(1.0, 0.86, 0.42, 0.5),
-                        custom_size: Some(Vec2::new(
-                            NUM_LARGE_BOX_WIDTH as f32 * GRID_LARGE_BOX_LENGTH as f32,
-                            1.5,
-                        )),
-                        ..default()
-                    },
-                    ..default()
-                });
-            }
-        });
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: meshes
+                .add(shape::Quad::new(size).into())
+                .into(),
+            material: materials.add(GridMaterial {
+                background_color: grid_settings.background_color,
+                small_line_color: grid_settings.small_line_color,
+                large_line_color: grid_settings.large_line_color,
+                small_box_length: grid_settings.small_box_length,
+                large_box_length: grid_settings.large_box_length,
+                small_line_width: grid_settings.small_line_width,
+                large_line_width: grid_settings.large_line_width,
+            }),
+            transform: Transform::from_translation(grid_settings.center().extend(0.0)),
+            ..default()
+        },
+        Grid {},
+        Name::from("Grid"),
+    ));
 }
 
 pub fn despawn_grid(grid_query: Query<Entity, With<Grid>>, mut commands: Commands) {
@@ -111,3 +45,19 @@ pub fn despawn_grid(grid_query: Query<Entity, With<Grid>>, mut commands: Command
         commands.entity(entity).despawn_recursive();
     }
 }
+
+pub fn follow_camera(
+    mut grid_query: Query<&mut Transform, (With<Grid>, Without<Camera>)>,
+    camera_query: Query<&Transform, (With<Camera>, Without<Grid>)>,
+) {
+    if let Ok(mut grid_transform) = grid_query.get_single_mut() {
+        if let Ok(camera_transform) = camera_query.get_single() {
+            grid_transform.translation.x = camera_transform.translation.x;
+            grid_transform.translation.y = camera_transform.translation.y;
+        }
+    }
+}
+
+pub fn unbounded_world(grid_settings: Res<GridSettings>) -> bool {
+    grid_settings.mode != WorldMode::Bounded
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Event;
+
+/// Sent when the player quits a run from the pause menu, which ends it for good.
+#[derive(Event)]
+pub struct AbandonRunEvent;
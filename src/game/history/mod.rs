@@ -0,0 +1,26 @@
+pub mod events;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{events::AbandonRunEvent, systems::end_run};
+
+/// Finished runs, one JSON object per line, oldest first.
+pub const HISTORY_FILE: &str = "runs.jsonl";
+/// Written into every run record. Bump it if a field is renamed, removed or changes meaning;
+/// adding fields doesn't need it.
+pub const RUN_RECORD_VERSION: u32 = 1;
+
+/// Ends the run when the player dies or abandons it, appending it to the run history for
+/// the records screen. Suspended runs aren't finished, so aren't recorded.
+pub struct HistoryPlugin;
+
+impl Plugin for HistoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AbandonRunEvent>()
+            .add_systems(Update, end_run.run_if(in_state(AppState::Game)));
+    }
+}
@@ -0,0 +1,134 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config,
+    game::{enemies::components::EnemyKind, player::abilities::components::DamageSource},
+};
+
+use super::HISTORY_FILE;
+
+/// One finished run, as a line of the history file. The file is meant to be read by other
+/// tools too, so fields are only added, never renamed, and `version` marks any other change.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub version: u32,
+    /// When the run ended, in seconds since the Unix epoch.
+    pub date: u64,
+    /// The character's id.
+    pub character: String,
+    pub seed: u64,
+    /// Seconds spent playing, not counting pauses and menus.
+    pub duration: f32,
+    pub level: usize,
+    pub kills: usize,
+    pub damage_by_weapon: BTreeMap<DamageSource, f32>,
+    /// The enemy that landed the last hit, or `None` if the run was abandoned.
+    pub cause_of_death: Option<EnemyKind>,
+}
+
+impl RunRecord {
+    /// Adds the run to the end of the history file, creating it if needed. A last line left
+    /// unfinished, say by a crash, is ended first so the new record starts on its own line.
+    pub fn append(&self) {
+        let Some(path) = config::data_path(HISTORY_FILE) else {
+            warn!("Couldn't record the run: no data directory");
+            return;
+        };
+        let result = serde_json::to_string(self)
+            .map_err(io::Error::from)
+            .and_then(|line| {
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent)?;
+                }
+                let mut file = OpenOptions::new()
+                    .read(true)
+                    .create(true)
+                    .append(true)
+                    .open(&path)?;
+                if file.metadata()?.len() > 0 {
+                    let mut last = [0; 1];
+                    file.seek(SeekFrom::End(-1))?;
+                    file.read_exact(&mut last)?;
+                    if last[0] != b'\n' {
+                        writeln!(file)?;
+                    }
+                }
+                writeln!(file, "{}", line)
+            });
+        if let Err(error) = result {
+            warn!("Couldn't record the run to {}: {}", path.display(), error);
+        }
+    }
+}
+
+/// Every recorded run, read from the history file when the records screen is opened.
+#[derive(Resource, Debug, Default)]
+pub struct RunHistory(pub Vec<RunRecord>);
+
+impl RunHistory {
+    /// Lines that can't be read, say from a crash mid-write, are skipped so the rest survive.
+    pub fn load() -> Self {
+        let Some(path) = config::data_path(HISTORY_FILE) else {
+            return RunHistory::default();
+        };
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return RunHistory::default(),
+            Err(error) => {
+                warn!("Couldn't read run history {}: {}", path.display(), error);
+                return RunHistory::default();
+            }
+        };
+
+        let runs = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .filter_map(|(index, line)| match serde_json::from_str(line) {
+                Ok(run) => Some(run),
+                Err(error) => {
+                    warn!("Skipping line {} of {}: {}", index + 1, path.display(), error);
+                    None
+                }
+            })
+            .collect();
+        RunHistory(runs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn append_ends_an_unfinished_last_line() {
+        let path = config::data_path(HISTORY_FILE).unwrap();
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "{\"version\": 1, \"da").unwrap();
+        let record = RunRecord {
+            version: 1,
+            date: 0,
+            character: "square".to_string(),
+            seed: 7,
+            duration: 60.0,
+            level: 3,
+            kills: 12,
+            damage_by_weapon: BTreeMap::new(),
+            cause_of_death: None,
+        };
+
+        record.append();
+
+        let history = RunHistory::load();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(history.0.len(), 1);
+        assert_eq!(history.0[0].seed, 7);
+    }
+}
@@ -0,0 +1,65 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use bevy::prelude::*;
+
+use crate::{
+    game::{
+        player::{components::Player, events::PlayerDeathEvent, resources::SelectedCharacter},
+        resources::GameRng,
+        states::GameState,
+        stats::resources::RunStats,
+    },
+    save::resources::SaveData,
+};
+
+use super::{events::AbandonRunEvent, resources::RunRecord, RUN_RECORD_VERSION};
+
+/// Records the run and leaves it once the player has died or abandoned it.
+#[allow(clippy::too_many_arguments)]
+pub fn end_run(
+    mut player_death_event_reader: EventReader<PlayerDeathEvent>,
+    mut abandon_run_event_reader: EventReader<AbandonRunEvent>,
+    player_query: Query<&Player>,
+    selected_character: Res<SelectedCharacter>,
+    game_rng: Res<GameRng>,
+    run_stats: Res<RunStats>,
+    mut save_data: ResMut<SaveData>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    let cause_of_death = match player_death_event_reader.iter().next() {
+        Some(event) => Some(event.killed_by),
+        None if !abandon_run_event_reader.is_empty() => None,
+        None => return,
+    };
+    player_death_event_reader.clear();
+    abandon_run_event_reader.clear();
+
+    let date = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    RunRecord {
+        version: RUN_RECORD_VERSION,
+        date,
+        character: selected_character.id.clone(),
+        seed: game_rng.seed,
+        duration: run_stats.time,
+        level: player_query.get_single().map(|player| player.lv).unwrap_or_default(),
+        kills: run_stats.kills,
+        damage_by_weapon: run_stats
+            .damage_by_source
+            .iter()
+            .map(|(source, damage)| (*source, *damage))
+            .collect(),
+        cause_of_death,
+    }
+    .append();
+
+    let best_time = save_data
+        .best_times
+        .entry(selected_character.id.clone())
+        .or_default();
+    *best_time = best_time.max(run_stats.time);
+
+    next_game_state.set(GameState::Inactive);
+}
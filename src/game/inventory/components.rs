@@ -0,0 +1,102 @@
+use bevy::prelude::Component;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    evolutions::Evolution,
+    items::{Passive, Weapon},
+    PASSIVE_SLOTS, WEAPON_SLOTS,
+};
+
+/// The weapons and passive items the player owns, with their ranks.
+#[derive(Component, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Inventory {
+    pub weapons: Vec<(Weapon, usize)>,
+    pub passives: Vec<(Passive, usize)>,
+    /// Weapons that have been evolved away and can't be picked up again.
+    pub evolved: Vec<Weapon>,
+}
+
+impl Inventory {
+    pub fn with_weapon(weapon: Weapon) -> Self {
+        Inventory {
+            weapons: vec![(weapon, 1)],
+            ..Default::default()
+        }
+    }
+
+    /// The rank of an owned weapon, or zero if it isn't owned.
+    pub fn weapon_rank(&self, weapon: Weapon) -> usize {
+        rank_of(&self.weapons, weapon)
+    }
+
+    /// The rank of an owned passive, or zero if it isn't owned.
+    pub fn passive_rank(&self, passive: Passive) -> usize {
+        rank_of(&self.passives, passive)
+    }
+
+    /// Whether the weapon can be added or ranked up: it isn't maxed, and either it's owned or
+    /// there's a free weapon slot.
+    pub fn can_rank_up_weapon(&self, weapon: Weapon) -> bool {
+        !self.evolved.contains(&weapon)
+            && can_rank_up(&self.weapons, weapon, weapon.max_rank(), WEAPON_SLOTS)
+    }
+
+    /// Whether the passive can be added or ranked up: it isn't maxed, and either it's owned or
+    /// there's a free passive slot.
+    pub fn can_rank_up_passive(&self, passive: Passive) -> bool {
+        can_rank_up(&self.passives, passive, passive.max_rank(), PASSIVE_SLOTS)
+    }
+
+    /// Adds a weapon at rank one, or ranks up one that's already owned. Returns whether
+    /// anything changed.
+    pub fn rank_up_weapon(&mut self, weapon: Weapon) -> bool {
+        if !self.can_rank_up_weapon(weapon) {
+            return false;
+        }
+        rank_up(&mut self.weapons, weapon);
+        true
+    }
+
+    /// Adds a passive at rank one, or ranks up one that's already owned. Returns whether
+    /// anything changed.
+    pub fn rank_up_passive(&mut self, passive: Passive) -> bool {
+        if !self.can_rank_up_passive(passive) {
+            return false;
+        }
+        rank_up(&mut self.passives, passive);
+        true
+    }
+
+    /// Replaces the evolution's weapon with what it evolves into. The passive is kept.
+    pub fn evolve(&mut self, evolution: &Evolution) {
+        if let Some(entry) = self
+            .weapons
+            .iter_mut()
+            .find(|(weapon, _)| *weapon == evolution.weapon)
+        {
+            *entry = (evolution.evolves_into, 1);
+            self.evolved.push(evolution.weapon);
+        }
+    }
+}
+
+fn rank_of<T: PartialEq>(items: &[(T, usize)], item: T) -> usize {
+    items
+        .iter()
+        .find(|(owned, _)| *owned == item)
+        .map_or(0, |(_, rank)| *rank)
+}
+
+fn can_rank_up<T: PartialEq>(items: &[(T, usize)], item: T, max_rank: usize, slots: usize) -> bool {
+    match items.iter().find(|(owned, _)| *owned == item) {
+        Some((_, rank)) => *rank < max_rank,
+        None => items.len() < slots,
+    }
+}
+
+fn rank_up<T: PartialEq>(items: &mut Vec<(T, usize)>, item: T) {
+    match items.iter_mut().find(|(owned, _)| *owned == item) {
+        Some((_, rank)) => *rank += 1,
+        None => items.push((item, 1)),
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    reflect::{TypePath, TypeUuid},
+    utils::BoxedFuture,
+};
+use serde::Deserialize;
+
+use super::{
+    components::Inventory,
+    items::{Passive, Weapon},
+};
+
+/// A maxed weapon plus a passive item evolves the weapon into something stronger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct Evolution {
+    pub weapon: Weapon,
+    pub passive: Passive,
+    pub evolves_into: Weapon,
+}
+
+impl Evolution {
+    pub fn eligible(&self, inventory: &Inventory) -> bool {
+        inventory.weapon_rank(self.weapon) >= self.weapon.max_rank()
+            && inventory.passive_rank(self.passive) > 0
+    }
+}
+
+/// All evolution recipes, loaded from an `.evolutions.ron` asset.
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "5c8e1f3a-9b2d-4e7c-a6f0-3d1b8c4e7a92"]
+pub struct Evolutions {
+    pub recipes: Vec<Evolution>,
+}
+
+impl Evolutions {
+    /// The evolutions the player could take right now.
+    pub fn eligible<'a>(&'a self, inventory: &'a Inventory) -> impl Iterator<Item = Evolution> + 'a {
+        self.recipes
+            .iter()
+            .filter(|evolution| evolution.eligible(inventory))
+            .copied()
+    }
+}
+
+#[derive(Default)]
+pub struct EvolutionsLoader;
+
+impl AssetLoader for EvolutionsLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let evolutions = ron::de::from_bytes::<Evolutions>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(evolutions));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["evolutions.ron"]
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::player::stats::{Modifier, ModifierKind, ModifierSource, Stat};
+
+use super::{
+    HASTE_COOLDOWN_PER_RANK, MAGNET_PICKUP_RADIUS_PER_RANK, MIGHT_DAMAGE_PER_RANK,
+    PASSIVE_MAX_RANK, SWIFTNESS_SPEED_PER_RANK, WEAPON_MAX_RANK,
+};
+
+/// Weapons the player can own. Evolved weapons can't be ranked up further.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Weapon {
+    Dot,
+    DotBurst,
+}
+
+impl Weapon {
+    pub fn max_rank(&self) -> usize {
+        match self {
+            Weapon::Dot => WEAPON_MAX_RANK,
+            Weapon::DotBurst => 1,
+        }
+    }
+}
+
+impl Display for Weapon {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Weapon::Dot => write!(f, "Dot"),
+            Weapon::DotBurst => write!(f, "Dot Burst"),
+        }
+    }
+}
+
+/// Passive items the player can own, which boost their stats and unlock evolutions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Passive {
+    Might,
+    Haste,
+    Swiftness,
+    Magnet,
+}
+
+impl Passive {
+    pub fn max_rank(&self) -> usize {
+        PASSIVE_MAX_RANK
+    }
+
+    /// The stat modifier this passive gives at the given rank.
+    pub fn modifier(&self, rank: usize) -> Modifier {
+        let rank = rank as f32;
+        let (stat, kind) = match self {
+            Passive::Might => (Stat::Damage, ModifierKind::Additive(MIGHT_DAMAGE_PER_RANK * rank)),
+            Passive::Haste => (
+                Stat::Cooldown,
+                ModifierKind::Additive(-HASTE_COOLDOWN_PER_RANK * rank),
+            ),
+            Passive::Swiftness => (
+                Stat::MoveSpeed,
+                ModifierKind::Multiplicative(1.0 + SWIFTNESS_SPEED_PER_RANK * rank),
+            ),
+            Passive::Magnet => (
+                Stat::PickupRadius,
+                ModifierKind::Multiplicative(1.0 + MAGNET_PICKUP_RADIUS_PER_RANK * rank),
+            ),
+        };
+        Modifier {
+            stat,
+            source: ModifierSource::Passive(*self),
+            kind,
+        }
+    }
+}
+
+impl Display for Passive {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Passive::Might => write!(f, "Might"),
+            Passive::Haste => write!(f, "Haste"),
+            Passive::Swiftness => write!(f, "Swiftness"),
+            Passive::Magnet => write!(f, "Magnet"),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+pub mod components;
+pub mod evolutions;
+pub mod items;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{
+    evolutions::{Evolutions, EvolutionsLoader},
+    systems::{load_evolutions, sync_passive_modifiers, sync_weapon_abilities},
+};
+
+pub const WEAPON_MAX_RANK: usize = 5;
+pub const PASSIVE_MAX_RANK: usize = 5;
+pub const WEAPON_SLOTS: usize = 3;
+pub const PASSIVE_SLOTS: usize = 3;
+pub const MIGHT_DAMAGE_PER_RANK: f32 = 0.1;
+pub const HASTE_COOLDOWN_PER_RANK: f32 = 0.08;
+/// Fractions of move speed and pickup radius added per rank.
+pub const SWIFTNESS_SPEED_PER_RANK: f32 = 0.1;
+pub const MAGNET_PICKUP_RADIUS_PER_RANK: f32 = 0.2;
+
+/// Tracks the player's weapons and passive items, and which weapons they can evolve.
+pub struct InventoryPlugin;
+
+impl Plugin for InventoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<Evolutions>()
+            .init_asset_loader::<EvolutionsLoader>()
+            .add_systems(Startup, load_evolutions)
+            .add_systems(
+                Update,
+                (sync_weapon_abilities, sync_passive_modifiers).run_if(in_state(AppState::Game)),
+            );
+    }
+}
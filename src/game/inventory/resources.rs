@@ -0,0 +1,6 @@
+use bevy::prelude::*;
+
+use super::evolutions::Evolutions;
+
+#[derive(Resource)]
+pub struct EvolutionsHandle(pub Handle<Evolutions>);
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+
+use crate::game::player::{
+    abilities::{dot::components::DotMod, dot_burst::components::DotBurstMod},
+    components::Player,
+    stats::{ModifierSource, PlayerStats},
+};
+
+use super::{components::Inventory, items::Weapon, resources::EvolutionsHandle};
+
+pub fn load_evolutions(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EvolutionsHandle(
+        asset_server.load("evolutions/weapons.evolutions.ron"),
+    ));
+}
+
+/// Gives the player the ability components for the weapons in their inventory, and takes
+/// away those for weapons they no longer own.
+#[allow(clippy::type_complexity)]
+pub fn sync_weapon_abilities(
+    mut commands: Commands,
+    player_query: Query<
+        (Entity, &Inventory, Option<&DotMod>, Option<&DotBurstMod>),
+        (With<Player>, Changed<Inventory>),
+    >,
+) {
+    if let Ok((player_entity, inventory, dot_mod, dot_burst_mod)) = player_query.get_single() {
+        let mut player = commands.entity(player_entity);
+
+        match (inventory.weapon_rank(Weapon::Dot) > 0, dot_mod.is_some()) {
+            (true, false) => {
+                player.insert(DotMod::default());
+            }
+            (false, true) => {
+                player.remove::<DotMod>();
+            }
+            _ => {}
+        }
+
+        match (inventory.weapon_rank(Weapon::DotBurst) > 0, dot_burst_mod.is_some()) {
+            (true, false) => {
+                player.insert(DotBurstMod::default());
+            }
+            (false, true) => {
+                player.remove::<DotBurstMod>();
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Rebuilds the stat modifiers from passive items whenever the inventory changes.
+#[allow(clippy::type_complexity)]
+pub fn sync_passive_modifiers(
+    mut player_query: Query<(&Inventory, &mut PlayerStats), (With<Player>, Changed<Inventory>)>,
+) {
+    if let Ok((inventory, mut stats)) = player_query.get_single_mut() {
+        for (passive, rank) in &inventory.passives {
+            stats.remove_modifiers(ModifierSource::Passive(*passive));
+            stats.add_modifier(passive.modifier(*rank));
+        }
+    }
+}
@@ -1,12 +1,12 @@
 use bevy::prelude::Component;
 
+use crate::game::upgrades::pool::Upgrade;
+
 #[derive(Component)]
 pub struct LevelUpMenu;
 
 #[derive(Component)]
 pub enum LevelUpMenuButton {
     Continue,
-    Upgrade1,
-    Upgrade2,
-    Upgrade3,
+    Upgrade(Upgrade),
 }
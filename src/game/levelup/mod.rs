@@ -8,6 +8,8 @@ use crate::{game::states::GameState, states::AppState};
 
 use self::systems::{button_interaction, despawn_level_up_menu, spawn_level_up_menu};
 
+pub const LEVEL_UP_CHOICES: usize = 3;
+
 pub struct LevelUpPlugin;
 
 impl Plugin for LevelUpPlugin {
@@ -1,13 +1,40 @@
 use bevy::prelude::*;
 
-use crate::game::states::GameState;
+use crate::focus::components::{FocusBack, Focusable};
+use crate::save::resources::SaveData;
+use crate::game::{
+    inventory::components::Inventory,
+    player::components::Player,
+    resources::GameRng,
+    states::GameState,
+    upgrades::{events::ApplyUpgradeEvent, pool::Upgrade},
+};
 
 use super::components::{LevelUpMenu, LevelUpMenuButton};
+use super::LEVEL_UP_CHOICES;
 use super::styles::{
     get_button_text, HOVERED_BUTTON_COLOR, LEVEL_UP_BUTTON_STYLE, LEVEL_UP_MENU_BACKGROUND_COLOR, LEVEL_UP_MENU_STYLE, LEVEL_UP_MENU_TRANSFORM, NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR
 };
 
-pub fn spawn_level_up_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_level_up_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    inventory_query: Query<&Inventory, With<Player>>,
+    save_data: Res<SaveData>,
+    mut game_rng: ResMut<GameRng>,
+) {
+    let upgrades = inventory_query
+        .get_single()
+        .map(|inventory| {
+            Upgrade::roll(
+                LEVEL_UP_CHOICES,
+                inventory,
+                &save_data.unlocked_weapons,
+                &mut game_rng.rng,
+            )
+        })
+        .unwrap_or_default();
+
     commands
         .spawn((
             NodeBundle {
@@ -39,9 +66,14 @@ pub fn spawn_level_up_menu(mut commands: Commands, asset_server: Res<AssetServer
                     ..default()
                 });
 
-                spawn_level_up_button(parent, &asset_server, "Upgrade 1", LevelUpMenuButton::Upgrade1);
-                spawn_level_up_button(parent, &asset_server, "Upgrade 2", LevelUpMenuButton::Upgrade2);
-                spawn_level_up_button(parent, &asset_server, "Upgrade 3", LevelUpMenuButton::Upgrade3);
+                for upgrade in upgrades {
+                    spawn_level_up_button(
+                        parent,
+                        &asset_server,
+                        &upgrade.to_string(),
+                        LevelUpMenuButton::Upgrade(upgrade),
+                    );
+                }
 
                 spawn_level_up_button(parent, &asset_server, "Continue", LevelUpMenuButton::Continue);
             });
@@ -54,21 +86,26 @@ fn spawn_level_up_button(
     text: &str,
     button_type: LevelUpMenuButton,
 ) {
-    parent
-        .spawn((
-            ButtonBundle {
-                style: LEVEL_UP_BUTTON_STYLE,
-                transform: LEVEL_UP_MENU_TRANSFORM,
-                ..default()
-            },
-            button_type,
-        ))
-        .with_children(|parent| {
-            parent.spawn(TextBundle {
-                text: get_button_text(asset_server, text),
-                ..default()
-            });
+    let is_continue = matches!(button_type, LevelUpMenuButton::Continue);
+    let mut button = parent.spawn((
+        ButtonBundle {
+            style: LEVEL_UP_BUTTON_STYLE,
+            transform: LEVEL_UP_MENU_TRANSFORM,
+            ..default()
+        },
+        button_type,
+        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+    ));
+    // Backing out skips the upgrade.
+    if is_continue {
+        button.insert(FocusBack);
+    }
+    button.with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: get_button_text(asset_server, text),
+            ..default()
         });
+    });
 }
 
 pub fn despawn_level_up_menu(
@@ -86,6 +123,7 @@ pub fn button_interaction(
         Changed<Interaction>,
     >,
     mut next_game_state: ResMut<NextState<GameState>>,
+    mut apply_upgrade_event_writer: EventWriter<ApplyUpgradeEvent>,
 ) {
     for (interaction, mut background_color, level_up_button) in button_query.iter_mut() {
         match (*interaction, level_up_button) {
@@ -93,17 +131,10 @@ pub fn button_interaction(
                 *background_color = PRESSED_BUTTON_COLOR.into();
                 next_game_state.set(GameState::Running);
             }
-            (Interaction::Pressed, LevelUpMenuButton::Upgrade1) => {
-                *background_color = PRESSED_BUTTON_COLOR.into();
-                // Apply upgrade 1
-            }
-            (Interaction::Pressed, LevelUpMenuButton::Upgrade2) => {
-                *background_color = PRESSED_BUTTON_COLOR.into();
-                // Apply upgrade 2
-            }
-            (Interaction::Pressed, LevelUpMenuButton::Upgrade3) => {
+            (Interaction::Pressed, LevelUpMenuButton::Upgrade(upgrade)) => {
                 *background_color = PRESSED_BUTTON_COLOR.into();
-                // Apply upgrade 3
+                apply_upgrade_event_writer.send(ApplyUpgradeEvent(*upgrade));
+                next_game_state.set(GameState::Running);
             }
             (Interaction::Hovered, _) => *background_color = HOVERED_BUTTON_COLOR.into(),
             _ => *background_color = NORMAL_BUTTON_COLOR.into(),
@@ -1,27 +1,43 @@
+pub mod achievements;
+mod chest;
 mod components;
 mod drops;
 mod enemies;
-mod grid;
+pub mod grid;
+pub mod history;
+pub mod inventory;
 mod paused;
-mod player;
+pub mod player;
 mod levelup;
-mod states;
+pub mod resources;
+pub mod states;
+mod stats;
+pub mod suspend;
 mod systems;
 mod ui;
+mod upgrades;
 
 use bevy::prelude::*;
 
 use crate::{states::AppState, systems::push_main_menu};
 
 use self::{
+    achievements::AchievementsPlugin,
+    chest::ChestMenuPlugin,
     drops::DropsPlugin,
     enemies::EnemiesPlugin,
     grid::GridPlugin,
+    history::HistoryPlugin,
+    inventory::InventoryPlugin,
     levelup::LevelUpPlugin,
     paused::PausedPlugin,
     player::PlayerPlugin,
+    resources::{GameRng, PendingMenus},
     states::GameState,
-    systems::{animate_sprites, deactivate_game, new_game, toggle_game_state, handle_player_level_up}, ui::UIPlugin,
+    stats::StatsPlugin,
+    suspend::SuspendPlugin,
+    systems::{animate_sprites, deactivate_game, new_game, seed_game_rng, toggle_game_state, handle_open_chest, handle_player_level_up, open_pending_menu}, ui::UIPlugin,
+    upgrades::UpgradesPlugin,
 };
 
 pub struct GamePlugin;
@@ -29,6 +45,8 @@ pub struct GamePlugin;
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
         app.add_state::<GameState>()
+            .init_resource::<GameRng>()
+            .init_resource::<PendingMenus>()
             .add_plugins((
                 PausedPlugin,
                 PlayerPlugin,
@@ -37,13 +55,24 @@ impl Plugin for GamePlugin {
                 DropsPlugin,
                 UIPlugin,
                 LevelUpPlugin,
+                ChestMenuPlugin,
+                StatsPlugin,
+                UpgradesPlugin,
+                InventoryPlugin,
+                SuspendPlugin,
+                AchievementsPlugin,
+                HistoryPlugin,
             ))
-            .add_systems(OnEnter(AppState::Game), new_game)
+            .add_systems(OnEnter(AppState::Game), (new_game, seed_game_rng))
             .add_systems(
                 Update,
                 (
                     toggle_game_state.run_if(in_state(AppState::Game)),
-                    (animate_sprites, handle_player_level_up).run_if(in_state(GameState::Running)),
+                    (
+                        animate_sprites,
+                        (handle_player_level_up, handle_open_chest, open_pending_menu).chain(),
+                    )
+                        .run_if(in_state(GameState::Running)),
                 ),
             )
             .add_systems(OnEnter(GameState::Inactive), push_main_menu)
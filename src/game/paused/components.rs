@@ -6,5 +6,7 @@ pub struct PauseMenu;
 #[derive(Component)]
 pub enum PauseMenuButton {
     Running,
+    Settings,
+    SaveAndQuit,
     MainMenu,
 }
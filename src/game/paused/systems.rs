@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 
-use crate::game::states::GameState;
+use crate::{
+    focus::components::{FocusBack, Focusable},
+    game::{
+        history::events::AbandonRunEvent, states::GameState, suspend::events::SuspendRunEvent,
+    },
+};
 
 use super::components::{PauseMenu, PauseMenuButton};
 use super::styles::{
@@ -54,6 +59,8 @@ pub fn spawn_pause_menu(
                             ..default()
                         },
                         PauseMenuButton::Running,
+                        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                        FocusBack,
                     ))
                     .with_children(|parent| {
                         parent.spawn(TextBundle {
@@ -61,6 +68,40 @@ pub fn spawn_pause_menu(
                             ..default()
                         });
                     });
+                // ---- Settings Button ----
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: PAUSE_BUTTON_STYLE,
+                            transform: PAUSE_MENU_TRANSFORM,
+                            ..default()
+                        },
+                        PauseMenuButton::Settings,
+                        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text: get_button_text(&asset_server, "Settings"),
+                            ..default()
+                        });
+                    });
+                // ---- Save & Quit Button ----
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: PAUSE_BUTTON_STYLE,
+                            transform: PAUSE_MENU_TRANSFORM,
+                            ..default()
+                        },
+                        PauseMenuButton::SaveAndQuit,
+                        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text: get_button_text(&asset_server, "Save & Quit"),
+                            ..default()
+                        });
+                    });
                 // ---- Quit Button ----
                 parent
                     .spawn((
@@ -70,6 +111,7 @@ pub fn spawn_pause_menu(
                             ..default()
                         },
                         PauseMenuButton::MainMenu,
+                        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
                     ))
                     .with_children(|parent| {
                         parent.spawn(TextBundle {
@@ -95,7 +137,10 @@ pub fn button_interaction(
         (&Interaction, &mut BackgroundColor, &PauseMenuButton),
         Changed<Interaction>,
     >,
+    current_game_state: Res<State<GameState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
+    mut suspend_run_event_writer: EventWriter<SuspendRunEvent>,
+    mut abandon_run_event_writer: EventWriter<AbandonRunEvent>,
 ) {
     for (interaction, mut background_color, pause_button_option) in button_query.iter_mut() {
         match (*interaction, pause_button_option) {
@@ -103,9 +148,22 @@ pub fn button_interaction(
                 *background_color = PRESSED_BUTTON_COLOR.into();
                 next_game_state.set(GameState::Running);
             }
+            (Interaction::Pressed, PauseMenuButton::Settings) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_game_state.set(GameState::Settings);
+            }
+            (Interaction::Pressed, PauseMenuButton::SaveAndQuit) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                suspend_run_event_writer.send(SuspendRunEvent);
+            }
             (Interaction::Pressed, PauseMenuButton::MainMenu) => {
                 *background_color = PRESSED_BUTTON_COLOR.into();
-                next_game_state.set(GameState::Inactive);
+                // A run still on its start screen hasn't begun, so there's nothing to record.
+                if *current_game_state.get() == GameState::Paused {
+                    abandon_run_event_writer.send(AbandonRunEvent);
+                } else {
+                    next_game_state.set(GameState::Inactive);
+                }
             }
             (Interaction::Hovered, _) => *background_color = HOVERED_BUTTON_COLOR.into(),
             _ => *background_color = NORMAL_BUTTON_COLOR.into(),
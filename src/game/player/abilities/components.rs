@@ -1,16 +1,27 @@
-use bevy::prelude::{Component, Vec3};
+use bevy::prelude::{Component, Entity, Vec3};
+use serde::{Deserialize, Serialize};
 
 use super::DEFAULT_ABILITY_SPEED;
 
-#[derive(Component)]
+/// Which ability dealt a hit, for kill attribution and stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DamageSource {
+    Dot,
+    DotBurst,
+}
+
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Ability {
     pub damage: f32,
+    pub source: DamageSource,
 }
 
-#[derive(Component)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Projectile {
     pub speed: f32,
     pub direction: Vec3,
+    /// How far it has flown, for culling where the world has no edges to cull at.
+    pub travelled: f32,
 }
 
 impl Default for Projectile {
@@ -18,6 +29,13 @@ impl Default for Projectile {
         Projectile {
             speed: DEFAULT_ABILITY_SPEED,
             direction: Vec3::ZERO,
+            travelled: 0.0,
         }
     }
 }
+
+/// A projectile that passes through enemies, hitting each one at most once.
+#[derive(Component, Default)]
+pub struct Piercing {
+    pub hit: Vec<Entity>,
+}
@@ -2,6 +2,8 @@ use std::time::Duration;
 
 use bevy::prelude::Component;
 
+use super::DOT_INTERVAL;
+
 #[derive(Component)]
 pub struct Dot;
 
@@ -9,3 +11,11 @@ pub struct Dot;
 pub struct DotMod {
     pub interval: Duration,
 }
+
+impl Default for DotMod {
+    fn default() -> Self {
+        DotMod {
+            interval: DOT_INTERVAL,
+        }
+    }
+}
@@ -1,5 +1,7 @@
 pub mod components;
-mod systems;
+pub mod systems;
+
+use std::time::Duration;
 
 use bevy::prelude::*;
 
@@ -11,6 +13,9 @@ use self::systems::{
 };
 
 pub const DEFAULT_DOT_RADIUS: f32 = 2.0;
+pub const DOT_DAMAGE: f32 = 100.0;
+pub const DOT_DAMAGE_PER_RANK: f32 = 25.0;
+pub const DOT_INTERVAL: Duration = Duration::from_millis(1000);
 
 pub struct DotPlugin;
 
@@ -22,10 +27,9 @@ impl Plugin for DotPlugin {
                 move_dots,
                 enemy_impact,
                 check_bounds,
-                spawn_dot.run_if(spawn_dot_condition),
+                spawn_dot.run_if(has_dot_mod).run_if(spawn_dot_condition),
             )
-                .run_if(in_state(GameState::Running))
-                .run_if(has_dot_mod),
+                .run_if(in_state(GameState::Running)),
         )
         .add_systems(OnExit(AppState::Game), despawn_dots);
     }
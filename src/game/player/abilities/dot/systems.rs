@@ -1,110 +1,186 @@
 use crate::game::player::abilities::dot::components::DotMod;
-use crate::game::player::components::Player;
+use crate::game::player::{
+    components::Player,
+    stats::{PlayerStats, Stat},
+    MIN_COOLDOWN_MULTIPLIER,
+};
 use bevy::{
     prelude::*,
     sprite::{collide_aabb::collide, MaterialMesh2dBundle},
+    window::PrimaryWindow,
 };
 use rand::seq::IteratorRandom;
 
 use crate::game::{
     enemies::components::Enemy,
-    grid::{GRID_HEIGHT, GRID_WIDTH},
+    grid::resources::{GridSettings, WorldMode},
+    resources::GameRng,
+    inventory::{components::Inventory, items::Weapon},
     player::abilities::{
-        components::{Ability, Projectile},
+        components::{Ability, DamageSource, Piercing, Projectile},
         events::TransmitDamage,
+        resources::WeaponCooldowns,
         DEFAULT_ABILITY_SPEED,
     },
 };
 
-use super::{components::Dot, DEFAULT_DOT_RADIUS};
+use super::{components::Dot, DEFAULT_DOT_RADIUS, DOT_DAMAGE, DOT_DAMAGE_PER_RANK};
 
 pub fn spawn_dot(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    player_query: Query<&Transform, With<Player>>,
+    player_query: Query<(&Transform, &Inventory, &PlayerStats), With<Player>>,
     enemy_query: Query<(&Transform, &Enemy), With<Enemy>>,
+    mut game_rng: ResMut<GameRng>,
+    mut weapon_cooldowns: ResMut<WeaponCooldowns>,
 ) {
-    if let Ok(player_transform) = player_query.get_single() {
-        if let Some((random_enemy_transform, _)) = enemy_query
+    weapon_cooldowns.fired(Weapon::Dot);
+
+    if let Ok((player_transform, inventory, stats)) = player_query.get_single() {
+        let rank = inventory.weapon_rank(Weapon::Dot).max(1);
+        let damage =
+            (DOT_DAMAGE + DOT_DAMAGE_PER_RANK * (rank - 1) as f32) * stats.get(Stat::Damage);
+        let scale = 2.0 * stats.get(Stat::Area);
+        let count = stats.get(Stat::ProjectileCount).round().max(1.0) as usize;
+
+        for (random_enemy_transform, _) in enemy_query
             .iter()
             .filter(|(_, enemy)| enemy.targetable)
-            .choose(&mut rand::thread_rng())
+            .choose_multiple(&mut game_rng.rng, count)
         {
-            commands.spawn((
-                Dot {},
-                Name::from("Dot"),
+            spawn_dot_projectile(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                player_transform.translation - Vec3::Z,
+                Vec3::new(scale, scale, 1.0),
                 Projectile {
                     speed: DEFAULT_ABILITY_SPEED,
                     direction: player_transform.translation - random_enemy_transform.translation,
-                },
-                Ability { damage: 100.0 },
-                MaterialMesh2dBundle {
-                    mesh: meshes
-                        .add(shape::Circle::new(DEFAULT_DOT_RADIUS).into())
-                        .into(),
-                    material: materials.add(ColorMaterial::from(Color::BLACK)),
-                    transform: Transform::from_xyz(
-                        player_transform.translation.x,
-                        player_transform.translation.y,
-                        player_transform.translation.z - 1.0,
-                    )
-                    .with_scale(Vec3 {
-                        x: 2.0,
-                        y: 2.0,
-                        z: 1.0,
-                    }),
                     ..default()
                 },
-            ));
+                Ability {
+                    damage,
+                    source: DamageSource::Dot,
+                },
+            );
         }
     }
 }
 
+/// Spawns a single dot, named after the weapon that fired it.
+pub fn spawn_dot_projectile(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    translation: Vec3,
+    scale: Vec3,
+    projectile: Projectile,
+    ability: Ability,
+) -> Entity {
+    let name = match ability.source {
+        DamageSource::Dot => "Dot",
+        DamageSource::DotBurst => "Dot Burst",
+    };
+    commands
+        .spawn((
+            Dot {},
+            Name::from(name),
+            projectile,
+            ability,
+            MaterialMesh2dBundle {
+                mesh: meshes
+                    .add(shape::Circle::new(DEFAULT_DOT_RADIUS).into())
+                    .into(),
+                material: materials.add(ColorMaterial::from(Color::BLACK)),
+                transform: Transform::from_translation(translation).with_scale(scale),
+                ..default()
+            },
+        ))
+        .id()
+}
+
 pub fn despawn_dots(mut commands: Commands, dots_query: Query<Entity, With<Dot>>) {
     for entity in &dots_query {
         commands.entity(entity).despawn_recursive();
     }
 }
 
-pub fn move_dots(mut dots_query: Query<(&mut Transform, &Projectile), With<Dot>>, time: Res<Time>) {
-    for (mut dot_transform, projectile) in &mut dots_query {
-        dot_transform.translation -=
-            projectile.direction.normalize_or_zero() * projectile.speed * time.delta_seconds();
+/// Moves dots along, wrapping them around the grid in a toroidal world like everything else.
+pub fn move_dots(
+    mut dots_query: Query<(&mut Transform, &mut Projectile), With<Dot>>,
+    grid_settings: Res<GridSettings>,
+    time: Res<Time>,
+) {
+    for (mut dot_transform, mut projectile) in &mut dots_query {
+        let distance = projectile.speed * time.delta_seconds();
+        dot_transform.translation = grid_settings.wrap(
+            dot_transform.translation - projectile.direction.normalize_or_zero() * distance,
+        );
+        projectile.travelled += distance;
     }
 }
 pub fn enemy_impact(
     mut commands: Commands,
     mut enemies_query: Query<(Entity, &Transform, &Handle<TextureAtlas>), With<Enemy>>,
-    dots_query: Query<(Entity, &Transform, &Ability), With<Dot>>,
+    mut dots_query: Query<(Entity, &Transform, &Ability, Option<&mut Piercing>), With<Dot>>,
     texture_atlases: Res<Assets<TextureAtlas>>,
     mut transmit_damage_event_writer: EventWriter<TransmitDamage>,
 ) {
-    for (dot_entity, dot_transform, dot_ability) in &dots_query {
+    for (dot_entity, dot_transform, dot_ability, mut piercing) in &mut dots_query {
         for (enemy_entity, enemy_transform, enemy_texture_atlas) in &mut enemies_query {
             if collide(
                 dot_transform.translation,
-                Vec2::splat(DEFAULT_DOT_RADIUS * 2.0),
+                // Dots are spawned at twice their radius, scaled further by area.
+                Vec2::splat(DEFAULT_DOT_RADIUS * dot_transform.scale.x),
                 enemy_transform.translation,
                 Vec2::splat(texture_atlases.get(enemy_texture_atlas).unwrap().size.y / 2.0),
             ) != None
             {
-                commands.entity(dot_entity).despawn_recursive();
+                match piercing.as_mut() {
+                    Some(piercing) if piercing.hit.contains(&enemy_entity) => continue,
+                    Some(piercing) => piercing.hit.push(enemy_entity),
+                    None => commands.entity(dot_entity).despawn_recursive(),
+                }
                 transmit_damage_event_writer.send(TransmitDamage {
                     target: enemy_entity,
                     damage: dot_ability.damage,
+                    source: dot_ability.source,
                 });
             }
         }
     }
 }
 
-pub fn check_bounds(mut commands: Commands, dots_query: Query<(Entity, &Transform), With<Dot>>) {
-    for (entity, transform) in &dots_query {
-        let x = transform.translation.x;
-        let y = transform.translation.y;
-        if x <= -10.0 || x >= (GRID_WIDTH as f32 + 10.0) || y <= -10.0 || y >= (GRID_HEIGHT as f32 + 10.0) {
-            commands.entity(entity).despawn_recursive();
+/// Culls dots that leave the play area. A toroidal world has no edge to leave, so there dots
+/// are culled once they've gone all the way around instead.
+pub fn check_bounds(
+    mut commands: Commands,
+    dots_query: Query<(Entity, &Transform, &Projectile), With<Dot>>,
+    camera_query: Query<&Transform, With<Camera>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    grid_settings: Res<GridSettings>,
+) {
+    if grid_settings.mode == WorldMode::Toroidal {
+        let range = grid_settings.size().max_element();
+        for (entity, _, projectile) in &dots_query {
+            if projectile.travelled >= range {
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+    } else if let (Ok(camera_transform), Ok(window)) = (camera_query.get_single(), window_query.get_single()) {
+        let play_area = grid_settings
+            .play_area(
+                camera_transform.translation.truncate(),
+                Vec2::new(window.width(), window.height()),
+            )
+            .inset(10.0);
+
+        for (entity, transform, _) in &dots_query {
+            if !play_area.contains(transform.translation.truncate()) {
+                commands.entity(entity).despawn_recursive();
+            }
         }
     }
 }
@@ -114,23 +190,12 @@ pub fn has_dot_mod(player_query: Query<&DotMod, With<Player>>) -> bool {
 }
 
 pub fn spawn_dot_condition(
-    player_query: Query<&DotMod, With<Player>>,
-    time: Res<Time>,
-    mut last_spawn_time: Local<Option<f32>>,
+    player_query: Query<(&DotMod, &PlayerStats), With<Player>>,
+    weapon_cooldowns: Res<WeaponCooldowns>,
 ) -> bool {
-    if let Ok(dot_mod) = player_query.get_single() {
-        let interval = dot_mod.interval.as_secs_f32();
-        let current_time = time.elapsed_seconds();
-
-        if let Some(last_time) = *last_spawn_time {
-            if current_time - last_time >= interval {
-                *last_spawn_time = Some(current_time);
-                return true;
-            }
-        } else {
-            *last_spawn_time = Some(current_time);
-            return true;
-        }
-    }
-    return false;
+    player_query.get_single().is_ok_and(|(dot_mod, stats)| {
+        let interval = dot_mod.interval.as_secs_f32()
+            * stats.get(Stat::Cooldown).max(MIN_COOLDOWN_MULTIPLIER);
+        weapon_cooldowns.ready(Weapon::Dot, interval)
+    })
 }
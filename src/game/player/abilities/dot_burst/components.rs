@@ -0,0 +1,19 @@
+use std::time::Duration;
+
+use bevy::prelude::Component;
+
+use super::DOT_BURST_INTERVAL;
+
+/// The evolved Dot: fires a ring of piercing dots in every direction.
+#[derive(Component)]
+pub struct DotBurstMod {
+    pub interval: Duration,
+}
+
+impl Default for DotBurstMod {
+    fn default() -> Self {
+        DotBurstMod {
+            interval: DOT_BURST_INTERVAL,
+        }
+    }
+}
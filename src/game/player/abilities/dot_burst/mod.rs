@@ -0,0 +1,29 @@
+pub mod components;
+mod systems;
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::game::states::GameState;
+
+use self::systems::{has_dot_burst_mod, spawn_dot_burst, spawn_dot_burst_condition};
+
+pub const DOT_BURST_COUNT: usize = 16;
+pub const DOT_BURST_DAMAGE: f32 = 150.0;
+pub const DOT_BURST_INTERVAL: Duration = Duration::from_millis(1500);
+
+/// Only spawns the burst; the dots themselves are moved, culled and collided by `DotPlugin`.
+pub struct DotBurstPlugin;
+
+impl Plugin for DotBurstPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            spawn_dot_burst
+                .run_if(has_dot_burst_mod)
+                .run_if(spawn_dot_burst_condition)
+                .run_if(in_state(GameState::Running)),
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+use crate::game::inventory::items::Weapon;
+use crate::game::player::{
+    abilities::{
+        components::{Ability, DamageSource, Piercing, Projectile},
+        dot::systems::spawn_dot_projectile,
+        resources::WeaponCooldowns,
+        DEFAULT_ABILITY_SPEED,
+    },
+    components::Player,
+    stats::{PlayerStats, Stat},
+    MIN_COOLDOWN_MULTIPLIER,
+};
+
+use super::{components::DotBurstMod, DOT_BURST_COUNT, DOT_BURST_DAMAGE};
+
+pub fn spawn_dot_burst(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    player_query: Query<(&Transform, &PlayerStats), With<Player>>,
+    mut weapon_cooldowns: ResMut<WeaponCooldowns>,
+) {
+    weapon_cooldowns.fired(Weapon::DotBurst);
+
+    if let Ok((player_transform, stats)) = player_query.get_single() {
+        let scale = 2.0 * stats.get(Stat::Area);
+        let count = DOT_BURST_COUNT * stats.get(Stat::ProjectileCount).round().max(1.0) as usize;
+
+        for i in 0..count {
+            let angle = i as f32 / count as f32 * TAU;
+
+            let dot = spawn_dot_projectile(
+                &mut commands,
+                &mut meshes,
+                &mut materials,
+                player_transform.translation - Vec3::Z,
+                Vec3::new(scale, scale, 1.0),
+                Projectile {
+                    speed: DEFAULT_ABILITY_SPEED,
+                    // Dots move against their direction.
+                    direction: -Vec3::new(angle.cos(), angle.sin(), 0.0),
+                    ..default()
+                },
+                Ability {
+                    damage: DOT_BURST_DAMAGE * stats.get(Stat::Damage),
+                    source: DamageSource::DotBurst,
+                },
+            );
+            commands.entity(dot).insert(Piercing::default());
+        }
+    }
+}
+
+pub fn has_dot_burst_mod(player_query: Query<&DotBurstMod, With<Player>>) -> bool {
+    player_query.get_single().is_ok()
+}
+
+pub fn spawn_dot_burst_condition(
+    player_query: Query<(&DotBurstMod, &PlayerStats), With<Player>>,
+    weapon_cooldowns: Res<WeaponCooldowns>,
+) -> bool {
+    player_query.get_single().is_ok_and(|(dot_burst_mod, stats)| {
+        let interval = dot_burst_mod.interval.as_secs_f32()
+            * stats.get(Stat::Cooldown).max(MIN_COOLDOWN_MULTIPLIER);
+        weapon_cooldowns.ready(Weapon::DotBurst, interval)
+    })
+}
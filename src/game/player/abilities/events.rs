@@ -1,7 +1,10 @@
-use bevy::prelude::{Event, Entity};
+use bevy::prelude::{Entity, Event};
+
+use super::components::DamageSource;
 
 #[derive(Event)]
 pub struct TransmitDamage {
     pub target: Entity,
     pub damage: f32,
+    pub source: DamageSource,
 }
\ No newline at end of file
@@ -1,18 +1,37 @@
-mod components;
+pub mod components;
 pub mod dot;
+pub mod dot_burst;
 pub mod events;
+pub mod resources;
+mod systems;
 
 use bevy::prelude::*;
 
-use self::{dot::DotPlugin, events::TransmitDamage};
+use crate::{game::states::GameState, states::AppState};
+
+use self::{
+    dot::DotPlugin,
+    dot_burst::DotBurstPlugin,
+    events::TransmitDamage,
+    resources::WeaponCooldowns,
+    systems::{reset_weapon_cooldowns, tick_weapon_cooldowns},
+};
 
 pub const DEFAULT_ABILITY_SPEED: f32 = 500.0;
 
+/// Weapon cooldowns start over with every game and only count down while it's running, so
+/// pausing doesn't charge them up.
 pub struct AbilitiesPlugin;
 
 impl Plugin for AbilitiesPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<TransmitDamage>()
-            .add_plugins(DotPlugin);
+            .init_resource::<WeaponCooldowns>()
+            .add_plugins((DotPlugin, DotBurstPlugin))
+            .add_systems(OnEnter(AppState::Game), reset_weapon_cooldowns)
+            .add_systems(
+                Update,
+                tick_weapon_cooldowns.run_if(in_state(GameState::Running)),
+            );
     }
 }
\ No newline at end of file
@@ -0,0 +1,22 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::game::inventory::items::Weapon;
+
+/// Seconds since each weapon last fired. Weapons that haven't fired yet this run are left out,
+/// so they fire straight away.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WeaponCooldowns(pub BTreeMap<Weapon, f32>);
+
+impl WeaponCooldowns {
+    /// Whether `weapon` has waited out `interval` seconds since it last fired.
+    pub fn ready(&self, weapon: Weapon, interval: f32) -> bool {
+        self.0.get(&weapon).map_or(true, |since_fired| *since_fired >= interval)
+    }
+
+    pub fn fired(&mut self, weapon: Weapon) {
+        self.0.insert(weapon, 0.0);
+    }
+}
@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+
+use super::resources::WeaponCooldowns;
+
+pub fn reset_weapon_cooldowns(mut weapon_cooldowns: ResMut<WeaponCooldowns>) {
+    *weapon_cooldowns = WeaponCooldowns::default();
+}
+
+pub fn tick_weapon_cooldowns(mut weapon_cooldowns: ResMut<WeaponCooldowns>, time: Res<Time>) {
+    for since_fired in weapon_cooldowns.0.values_mut() {
+        *since_fired += time.delta_seconds();
+    }
+}
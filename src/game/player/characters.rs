@@ -0,0 +1,110 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    prelude::Color,
+    reflect::{TypePath, TypeUuid},
+    utils::{BoxedFuture, HashMap},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{game::inventory::items::Weapon, save::resources::SaveData};
+
+use super::stats::{Modifier, ModifierKind, ModifierSource, PlayerStats, Stat};
+
+/// A stat bonus a character always has.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CharacterBonus {
+    pub stat: Stat,
+    pub kind: ModifierKind,
+}
+
+/// A playable character, defined in a `.characters.ron` asset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Character {
+    /// Stays the same when the name changes, for saves and run history.
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub sprite: String,
+    /// Tints the sprite, so characters can share one.
+    #[serde(default = "default_color")]
+    pub color: Color,
+    pub weapon: Weapon,
+    /// Base stats that differ from the defaults.
+    #[serde(default)]
+    pub base_stats: HashMap<Stat, f32>,
+    pub bonus: Option<CharacterBonus>,
+    /// Locked characters have to be unlocked by an achievement before they can be picked.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+fn default_color() -> Color {
+    Color::WHITE
+}
+
+impl Character {
+    pub fn is_unlocked(&self, save_data: &SaveData) -> bool {
+        !self.locked || save_data.unlocked_characters.contains(&self.id)
+    }
+
+    /// The stats a run with this character starts with.
+    pub fn stats(&self) -> PlayerStats {
+        let mut stats = PlayerStats::default();
+        for (stat, value) in &self.base_stats {
+            stats.set_base(*stat, *value);
+        }
+        if let Some(bonus) = self.bonus {
+            stats.add_modifier(Modifier {
+                stat: bonus.stat,
+                source: ModifierSource::Character,
+                kind: bonus.kind,
+            });
+        }
+        stats
+    }
+}
+
+impl Default for Character {
+    /// Used when the characters asset hasn't loaded: the original circle.
+    fn default() -> Self {
+        Character {
+            id: "circle".to_string(),
+            name: "Circle".to_string(),
+            description: String::new(),
+            sprite: "sprites/circle_player_4_frame_64x64.png".to_string(),
+            color: Color::WHITE,
+            weapon: Weapon::Dot,
+            base_stats: HashMap::new(),
+            bonus: None,
+            locked: false,
+        }
+    }
+}
+
+/// Every playable character, in the order they're listed.
+#[derive(Debug, Deserialize, TypeUuid, TypePath)]
+#[uuid = "e4a7c2d9-3f16-4b58-8d0e-71b5a9c3f264"]
+pub struct Characters {
+    pub characters: Vec<Character>,
+}
+
+#[derive(Default)]
+pub struct CharactersLoader;
+
+impl AssetLoader for CharactersLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let characters = ron::de::from_bytes::<Characters>(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(characters));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["characters.ron"]
+    }
+}
@@ -1,13 +1,16 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use super::DEFAULT_PLAYER_SPEED;
+use super::{DASH_COOLDOWN, DEFAULT_MAX_HEALTH, DEFAULT_PICKUP_RADIUS, PICKUP_COLLECT_RATIO};
 
-#[derive(Component, Debug)]
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     pub xp: (f32, f32),
     pub lv: usize,
+    /// Current and max health. Max health follows `Stat::MaxHealth`.
     pub health: (f32, f32),
-    pub speed: f32,
+    /// The last direction the player moved in, or zero if they haven't moved yet.
+    pub direction: Vec3,
 }
 
 impl Player {
@@ -21,9 +24,20 @@ impl Player {
         self.health.0 -= amount;
         if self.health.0 < 0.0 {
             self.health.0 = 0.0;
-            // Handle player death here if needed
         }
     }
+
+    pub fn heal(&mut self, amount: f32) {
+        self.health.0 = (self.health.0 + amount).min(self.health.1);
+    }
+
+    /// Changes max health. Raising it also heals by the same amount so the bar doesn't appear
+    /// to drop.
+    pub fn set_max_health(&mut self, max_health: f32) {
+        let increase = max_health - self.health.1;
+        self.health.1 = max_health;
+        self.health.0 = (self.health.0 + increase.max(0.0)).min(max_health);
+    }
 }
 
 impl Default for Player {
@@ -31,8 +45,72 @@ impl Default for Player {
         Player {
             xp: (0.0, 10.0),
             lv: 0,
-            health: (10.0, 10.0),
-            speed: DEFAULT_PLAYER_SPEED,
+            health: (DEFAULT_MAX_HEALTH, DEFAULT_MAX_HEALTH),
+            direction: Vec3::ZERO,
+        }
+    }
+}
+
+/// How far away drops start moving towards the player. Follows `Stat::PickupRadius`, kept
+/// separately so drop systems don't need the whole stats component.
+#[derive(Component, Debug, Deref, DerefMut)]
+pub struct PickupRadius(pub f32);
+
+impl Default for PickupRadius {
+    fn default() -> Self {
+        PickupRadius(DEFAULT_PICKUP_RADIUS)
+    }
+}
+
+impl PickupRadius {
+    /// Whether a drop at `drop` is close enough to a player at `player` to be collected.
+    pub fn collects(&self, player: Vec3, drop: Vec3) -> bool {
+        player.truncate().distance(drop.truncate()) <= self.0 * PICKUP_COLLECT_RATIO
+    }
+}
+
+/// The player can't take damage until the timer runs out.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct Invulnerable {
+    pub timer: Timer,
+    /// Whether the player flashes, to show they were hurt.
+    pub flash: bool,
+}
+
+impl Invulnerable {
+    pub fn new(seconds: f32) -> Self {
+        Invulnerable {
+            timer: Timer::from_seconds(seconds, TimerMode::Once),
+            flash: true,
+        }
+    }
+
+    pub fn without_flash(seconds: f32) -> Self {
+        Invulnerable {
+            flash: false,
+            ..Invulnerable::new(seconds)
         }
     }
-}
\ No newline at end of file
+}
+
+/// Lets the player dash once the cooldown has run out.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct Dash {
+    pub cooldown: Timer,
+}
+
+impl Default for Dash {
+    /// Starts off cooldown, so the player can dash straight away.
+    fn default() -> Self {
+        let mut cooldown = Timer::from_seconds(DASH_COOLDOWN, TimerMode::Once);
+        cooldown.tick(cooldown.duration());
+        Dash { cooldown }
+    }
+}
+
+/// The player is mid-dash, moving quickly in a fixed direction instead of following input.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct Dashing {
+    pub direction: Vec3,
+    pub timer: Timer,
+}
@@ -1,4 +1,16 @@
 use bevy::prelude::Event;
 
+use crate::game::enemies::components::EnemyKind;
+
+#[derive(Event)]
+pub struct PlayerLevelUpEvent(pub usize);
+
+/// Sent whenever an enemy hurts the player, with the damage after armor.
+#[derive(Event)]
+pub struct PlayerHurtEvent(pub f32);
+
+/// Sent once when an enemy brings the player's health to zero, which ends the run.
 #[derive(Event)]
-pub struct PlayerLevelUpEvent(pub usize);
\ No newline at end of file
+pub struct PlayerDeathEvent {
+    pub killed_by: EnemyKind,
+}
@@ -1,6 +1,9 @@
 pub mod abilities;
+pub mod characters;
 pub mod components;
 pub mod events;
+pub mod resources;
+pub mod stats;
 mod systems;
 
 use bevy::prelude::*;
@@ -9,13 +12,41 @@ use crate::states::AppState;
 
 use self::{
     abilities::AbilitiesPlugin,
-    events::PlayerLevelUpEvent,
-    systems::{add_xp, camera_follow, despawn_player, move_player, spawn_player, player_enemy_collision},
+    characters::{Characters, CharactersLoader},
+    events::{PlayerDeathEvent, PlayerHurtEvent, PlayerLevelUpEvent},
+    resources::{ScreenShake, SelectedCharacter},
+    systems::{
+        add_xp, apply_stats, camera_follow, despawn_player, draw_pickup_radius, heal_player,
+        load_characters, move_player, player_enemy_collision, regenerate_health, reset_screen_shake, spawn_player,
+        start_dash, tick_dash, tick_invulnerability, wrap_around_player,
+    },
 };
 
-use super::states::GameState;
+use super::{states::GameState, systems::toroidal_world};
 
 pub const DEFAULT_PLAYER_SPEED: f32 = 250.0;
+pub const DEFAULT_MAX_HEALTH: f32 = 10.0;
+/// The lowest `Stat::Cooldown` can bring weapon cooldowns to.
+pub const MIN_COOLDOWN_MULTIPLIER: f32 = 0.1;
+pub const DEFAULT_PICKUP_RADIUS: f32 = 200.0;
+/// Fraction of the pickup radius within which drops are actually collected.
+pub const PICKUP_COLLECT_RATIO: f32 = 0.08;
+/// How long the player can't be hurt again after taking a hit.
+pub const PLAYER_INVULNERABILITY_DURATION: f32 = 0.5;
+/// The least damage a hit can do, however much armor the player has.
+pub const PLAYER_MIN_DAMAGE_TAKEN: f32 = 0.5;
+pub const PLAYER_HURT_COLOR: Color = Color::RED;
+pub const PLAYER_HURT_FLASH_INTERVAL: f32 = 0.1;
+pub const DASH_DISTANCE: f32 = 200.0;
+pub const DASH_DURATION: f32 = 0.15;
+pub const DASH_COOLDOWN: f32 = 2.0;
+/// How much shake each hit on the player adds, out of a full shake of one.
+pub const SCREEN_SHAKE_TRAUMA_PER_HIT: f32 = 0.5;
+/// How far a full shake pushes the camera, in pixels.
+pub const SCREEN_SHAKE_MAX_OFFSET: f32 = 16.0;
+/// How much shake settles per second.
+pub const SCREEN_SHAKE_DECAY: f32 = 1.5;
+pub const PICKUP_RADIUS_DEBUG_COLOR: Color = Color::rgba(0.2, 0.6, 1.0, 0.3);
 
 pub struct PlayerPlugin;
 
@@ -25,13 +56,36 @@ impl Plugin for PlayerPlugin {
     /// * `app`: The Bevy app builder used to register the systems.
     fn build(&self, app: &mut App) {
         app.add_event::<PlayerLevelUpEvent>()
+            .add_event::<PlayerHurtEvent>()
+            .add_event::<PlayerDeathEvent>()
+            .add_asset::<Characters>()
+            .init_asset_loader::<CharactersLoader>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<SelectedCharacter>()
+            .add_systems(Startup, load_characters)
             .add_plugins(AbilitiesPlugin)
-            .add_systems(OnEnter(AppState::Game), spawn_player)
+            .add_systems(OnEnter(AppState::Game), (spawn_player, reset_screen_shake))
             .add_systems(
                 Update,
-                ((move_player, camera_follow).chain(), add_xp, player_enemy_collision)
+                (
+                    (
+                        start_dash,
+                        move_player,
+                        wrap_around_player.run_if(toroidal_world),
+                        camera_follow,
+                        tick_dash,
+                    )
+                        .chain(),
+                    add_xp,
+                    (regenerate_health, heal_player, player_enemy_collision, tick_invulnerability),
+                )
                     .run_if(in_state(GameState::Running)),
             )
+            .add_systems(Update, apply_stats.run_if(in_state(AppState::Game)))
             .add_systems(OnExit(AppState::Game), despawn_player);
+
+        if cfg!(debug_assertions) {
+            app.add_systems(Update, draw_pickup_radius.run_if(in_state(AppState::Game)));
+        }
     }
 }
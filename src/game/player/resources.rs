@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use super::characters::{Character, Characters};
+
+/// How much the camera is shaking, from zero to one. Hits add to it and it settles over time.
+#[derive(Resource, Debug, Default)]
+pub struct ScreenShake {
+    pub trauma: f32,
+}
+
+impl ScreenShake {
+    pub fn add_trauma(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma).min(1.0);
+    }
+
+    /// How far the camera is pushed at most this frame. Squaring the trauma keeps small shakes
+    /// subtle while big ones still kick.
+    pub fn offset(&self, max_offset: f32) -> f32 {
+        self.trauma * self.trauma * max_offset
+    }
+}
+
+#[derive(Resource)]
+pub struct CharactersHandle(pub Handle<Characters>);
+
+/// The character the next run is played as, picked on the character select screen.
+#[derive(Resource, Debug, Clone, Default, Deref)]
+pub struct SelectedCharacter(pub Character);
@@ -0,0 +1,109 @@
+use bevy::{prelude::Component, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{game::inventory::items::Passive, power_ups::items::PowerUp};
+
+use super::{DEFAULT_MAX_HEALTH, DEFAULT_PICKUP_RADIUS, DEFAULT_PLAYER_SPEED};
+
+/// Every player stat that can be modified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Stat {
+    MoveSpeed,
+    MaxHealth,
+    /// Health restored per second.
+    Regen,
+    PickupRadius,
+    /// Subtracted from every hit the player takes.
+    Armor,
+    /// Multiplies weapon cooldowns.
+    Cooldown,
+    /// Multiplies the size of weapon effects.
+    Area,
+    /// How many projectiles each weapon fires at once.
+    ProjectileCount,
+    /// Improves the odds of rare rewards.
+    Luck,
+    /// Multiplies all damage dealt.
+    Damage,
+}
+
+/// Where a modifier came from, so it can be replaced or removed later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModifierSource {
+    Upgrade,
+    Passive(Passive),
+    PowerUp(PowerUp),
+    /// The character's own bonus.
+    Character,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ModifierKind {
+    /// Added to the base value.
+    Additive(f32),
+    /// Multiplies the base value plus all additive modifiers.
+    Multiplicative(f32),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Modifier {
+    pub stat: Stat,
+    pub source: ModifierSource,
+    pub kind: ModifierKind,
+}
+
+/// The player's stats: a base value for each, plus modifiers stacked on top. A stat's final
+/// value is `(base + additive modifiers) * multiplicative modifiers`.
+#[derive(Component, Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerStats {
+    base: HashMap<Stat, f32>,
+    modifiers: Vec<Modifier>,
+}
+
+impl PlayerStats {
+    pub fn get(&self, stat: Stat) -> f32 {
+        let base = self.base.get(&stat).copied().unwrap_or_default();
+        let (additive, multiplicative) = self
+            .modifiers
+            .iter()
+            .filter(|modifier| modifier.stat == stat)
+            .fold((0.0, 1.0), |(additive, multiplicative), modifier| match modifier.kind {
+                ModifierKind::Additive(value) => (additive + value, multiplicative),
+                ModifierKind::Multiplicative(value) => (additive, multiplicative * value),
+            });
+        (base + additive) * multiplicative
+    }
+
+    pub fn set_base(&mut self, stat: Stat, value: f32) {
+        self.base.insert(stat, value);
+    }
+
+    /// Stacks a modifier on top of any existing ones from the same source.
+    pub fn add_modifier(&mut self, modifier: Modifier) {
+        self.modifiers.push(modifier);
+    }
+
+    pub fn remove_modifiers(&mut self, source: ModifierSource) {
+        self.modifiers.retain(|modifier| modifier.source != source);
+    }
+}
+
+impl Default for PlayerStats {
+    fn default() -> Self {
+        PlayerStats {
+            base: HashMap::from_iter([
+                (Stat::MoveSpeed, DEFAULT_PLAYER_SPEED),
+                (Stat::MaxHealth, DEFAULT_MAX_HEALTH),
+                (Stat::Regen, 0.0),
+                (Stat::PickupRadius, DEFAULT_PICKUP_RADIUS),
+                (Stat::Armor, 0.0),
+                (Stat::Cooldown, 1.0),
+                (Stat::Area, 1.0),
+                (Stat::ProjectileCount, 1.0),
+                (Stat::Luck, 1.0),
+                (Stat::Damage, 1.0),
+            ]),
+            modifiers: Vec::new(),
+        }
+    }
+}
@@ -1,21 +1,46 @@
 use bevy::prelude::*;
+use rand::Rng;
 
-use super::{abilities::dot::components::DotMod, components::*, events::PlayerLevelUpEvent};
+use super::{
+    abilities::components::Projectile,
+    components::*,
+    events::{PlayerDeathEvent, PlayerHurtEvent, PlayerLevelUpEvent},
+    resources::{CharactersHandle, ScreenShake, SelectedCharacter},
+    stats::{PlayerStats, Stat},
+    DASH_DISTANCE, DASH_DURATION, PICKUP_RADIUS_DEBUG_COLOR, PLAYER_HURT_COLOR, PLAYER_HURT_FLASH_INTERVAL,
+    PLAYER_INVULNERABILITY_DURATION, PLAYER_MIN_DAMAGE_TAKEN, SCREEN_SHAKE_DECAY,
+    SCREEN_SHAKE_MAX_OFFSET, SCREEN_SHAKE_TRAUMA_PER_HIT,
+};
 
 use crate::game::{
     components::{AnimationIndices, AnimationTimer},
-    drops::experience::events::SendExperienceEvent, grid::{GRID_HEIGHT, GRID_WIDTH},
-    enemies::components::Enemy,
+    drops::{
+        components::Drop, experience::events::SendExperienceEvent,
+        health::events::SendHealthEvent,
+    },
+    enemies::components::{ContactDamage, Enemy},
+    grid::resources::{GridSettings, WorldMode},
+    inventory::components::Inventory,
 };
+use crate::input::{actions::Action, gamepad::left_stick, resources::InputBindings};
+use crate::save::resources::SaveData;
+use crate::settings::resources::GameSettings;
 
-use std::time::Duration;
+pub fn load_characters(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(CharactersHandle(
+        asset_server.load("characters/shapes.characters.ron"),
+    ));
+}
 
 pub fn spawn_player(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    grid_settings: Res<GridSettings>,
+    save_data: Res<SaveData>,
+    selected_character: Res<SelectedCharacter>,
 ) {
-    let texture_handle = asset_server.load("sprites/circle_player_4_frame_64x64.png");
+    let texture_handle = asset_server.load(&selected_character.sprite);
     let texture_atlas =
         TextureAtlas::from_grid(texture_handle, Vec2::new(64.0, 64.0), 4, 1, None, None);
     let texture_atlas_handle = texture_atlases.add(texture_atlas);
@@ -25,13 +50,21 @@ pub fn spawn_player(
         reverse: false,
     };
 
+    let mut stats = selected_character.stats();
+    for (power_up, rank) in &save_data.power_ups {
+        stats.add_modifier(power_up.modifier(*rank));
+    }
+
     commands.spawn((
         Player::default(),
         Name::from("Player"),
         SpriteSheetBundle {
             texture_atlas: texture_atlas_handle,
-            sprite: TextureAtlasSprite::new(animation_indices.first),
-            transform: Transform::from_xyz(GRID_WIDTH as f32 / 2.0, GRID_HEIGHT as f32 / 2.0, 100.0)
+            sprite: TextureAtlasSprite {
+                color: selected_character.color,
+                ..TextureAtlasSprite::new(animation_indices.first)
+            },
+            transform: Transform::from_translation(grid_settings.center().extend(100.0))
                 .with_scale(Vec3 {
                     x: 0.5,
                     y: 0.5,
@@ -41,9 +74,10 @@ pub fn spawn_player(
         },
         animation_indices,
         AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
-        DotMod {
-            interval: Duration::from_millis(1000),
-        },
+        stats,
+        PickupRadius::default(),
+        Dash::default(),
+        Inventory::with_weapon(selected_character.weapon),
     ));
 }
 
@@ -53,59 +87,179 @@ pub fn despawn_player(player_query: Query<Entity, With<Player>>, mut commands: C
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn move_player(
     keyboard_input: Res<Input<KeyCode>>,
-    mut player_query: Query<(&mut Transform, &Handle<TextureAtlas>, &Player), With<Player>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    input_bindings: Res<InputBindings>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &Handle<TextureAtlas>,
+            &mut Player,
+            &PlayerStats,
+            Option<&Dashing>,
+        ),
+        With<Player>,
+    >,
     texture_atlases: Res<Assets<TextureAtlas>>,
+    grid_settings: Res<GridSettings>,
     time: Res<Time>,
 ) {
     let mut direction = Vec3::ZERO;
 
-    if let Ok((mut player_transform, texture_atlas_handle, player)) = player_query.get_single_mut() {
-        if keyboard_input.pressed(KeyCode::W) {
+    if let Ok((mut player_transform, texture_atlas_handle, mut player, stats, dashing)) =
+        player_query.get_single_mut()
+    {
+        if input_bindings.pressed_any(Action::MoveUp, &keyboard_input, &gamepad_buttons) {
             direction.y += 1.0;
         }
-        if keyboard_input.pressed(KeyCode::A) {
+        if input_bindings.pressed_any(Action::MoveLeft, &keyboard_input, &gamepad_buttons) {
             direction.x -= 1.0;
         }
-        if keyboard_input.pressed(KeyCode::S) {
+        if input_bindings.pressed_any(Action::MoveDown, &keyboard_input, &gamepad_buttons) {
             direction.y -= 1.0;
         }
-        if keyboard_input.pressed(KeyCode::D) {
+        if input_bindings.pressed_any(Action::MoveRight, &keyboard_input, &gamepad_buttons) {
             direction.x += 1.0;
         }
 
         if direction.length() > 0.0 {
             direction = direction.normalize();
+        } else {
+            // The stick is analog, so a light push walks slower.
+            direction = left_stick(&gamepads, &gamepad_axes).extend(0.0);
+        }
+        if direction.length() > 0.0 {
+            player.direction = direction.normalize();
+        }
+
+        // Dashes go through the same clamping and wrapping as walking.
+        let velocity = match dashing {
+            Some(dashing) => dashing.direction * DASH_DISTANCE / DASH_DURATION,
+            None => direction * stats.get(Stat::MoveSpeed),
+        };
+        let new_position = player_transform.translation + velocity * time.delta_seconds();
+
+        match grid_settings.mode {
+            WorldMode::Bounded => {
+                let texture_atlas = texture_atlases.get(texture_atlas_handle).unwrap();
+                let sprite_size = texture_atlas.size * player_transform.scale.truncate();
+                let half_width = sprite_size.x / 2.0 / texture_atlas.size.x * texture_atlas.size.y; // I think this is because the texture atlas is 4 frames wide.
+                let half_height = sprite_size.y / 2.0;
+
+                let clamped_x = new_position.x.clamp(half_width, grid_settings.width() - half_width);
+                let clamped_y = new_position.y.clamp(half_height, grid_settings.height() - half_height);
+
+                player_transform.translation.x = clamped_x;
+                player_transform.translation.y = clamped_y;
+            }
+            WorldMode::Infinite | WorldMode::Toroidal => {
+                player_transform.translation = grid_settings.wrap(new_position);
+            }
+        }
+    }
+}
+
+/// Keeps everything that wraps within half a world of the player, so entities on the far
+/// side of a toroidal world's seam are drawn next to the player rather than across the map.
+#[allow(clippy::type_complexity)]
+pub fn wrap_around_player(
+    player_query: Query<&Transform, With<Player>>,
+    mut wrapping_query: Query<
+        &mut Transform,
+        (Or<(With<Enemy>, With<Drop>, With<Projectile>)>, Without<Player>),
+    >,
+    grid_settings: Res<GridSettings>,
+) {
+    if let Ok(player_transform) = player_query.get_single() {
+        for mut transform in &mut wrapping_query {
+            transform.translation = player_transform.translation
+                + grid_settings.offset(player_transform.translation, transform.translation);
         }
+    }
+}
 
-        let new_position = player_transform.translation + direction * player.speed * time.delta_seconds();
+/// Starts a dash in the direction the player last moved, if it's off cooldown.
+pub fn start_dash(
+    mut commands: Commands,
+    keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    input_bindings: Res<InputBindings>,
+    player_query: Query<(Entity, &Player, &Dash, Option<&Invulnerable>), Without<Dashing>>,
+) {
+    if !input_bindings.just_pressed_any(Action::Dash, &keyboard_input, &gamepad_buttons) {
+        return;
+    }
 
-        let texture_atlas = texture_atlases.get(texture_atlas_handle).unwrap();
-        let sprite_size = texture_atlas.size * player_transform.scale.truncate();
-        let half_width = sprite_size.x / 2.0 / texture_atlas.size.x * texture_atlas.size.y; // I think this is because the texture atlas is 4 frames wide.
-        let half_height = sprite_size.y / 2.0;
+    if let Ok((player_entity, player, dash, invulnerable)) = player_query.get_single() {
+        if !dash.cooldown.finished() || player.direction == Vec3::ZERO {
+            return;
+        }
 
-        let clamped_x = new_position.x.clamp(half_width, GRID_WIDTH as f32 - half_width);
-        let clamped_y = new_position.y.clamp(half_height, GRID_HEIGHT as f32 - half_height);
+        let mut player_commands = commands.entity(player_entity);
+        player_commands.insert(Dashing {
+            direction: player.direction,
+            timer: Timer::from_seconds(DASH_DURATION, TimerMode::Once),
+        });
+        // Don't cut short a longer invulnerability from being hurt.
+        if invulnerable.map_or(true, |invulnerable| invulnerable.timer.remaining_secs() < DASH_DURATION) {
+            player_commands.insert(Invulnerable::without_flash(DASH_DURATION));
+        }
+    }
+}
 
-        player_transform.translation.x = clamped_x;
-        player_transform.translation.y = clamped_y;
+/// Ends dashes once they've covered their distance, and counts down the cooldown afterwards.
+pub fn tick_dash(
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &mut Dash, Option<&mut Dashing>), With<Player>>,
+    time: Res<Time>,
+) {
+    if let Ok((player_entity, mut dash, dashing)) = player_query.get_single_mut() {
+        match dashing {
+            Some(mut dashing) => {
+                if dashing.timer.tick(time.delta()).finished() {
+                    commands.entity(player_entity).remove::<Dashing>();
+                    dash.cooldown.reset();
+                }
+            }
+            None => {
+                dash.cooldown.tick(time.delta());
+            }
+        }
     }
 }
 
+/// Centers the camera on the player, knocked about by any screen shake.
 pub fn camera_follow(
     player_query: Query<&Transform, With<Player>>,
     mut camera_query: Query<&mut Transform, (With<Camera>, Without<Player>)>,
+    mut screen_shake: ResMut<ScreenShake>,
+    game_settings: Res<GameSettings>,
+    time: Res<Time>,
 ) {
+    let mut shake = Vec2::ZERO;
+    if game_settings.screen_shake && screen_shake.trauma > 0.0 {
+        let offset = screen_shake.offset(SCREEN_SHAKE_MAX_OFFSET);
+        let mut rng = rand::thread_rng();
+        shake = Vec2::new(rng.gen_range(-offset..=offset), rng.gen_range(-offset..=offset));
+    }
+    screen_shake.trauma = (screen_shake.trauma - SCREEN_SHAKE_DECAY * time.delta_seconds()).max(0.0);
+
     if let Ok(player_transform) = player_query.get_single() {
         if let Ok(mut camera_transform) = camera_query.get_single_mut() {
-            camera_transform.translation.x = player_transform.translation.x;
-            camera_transform.translation.y = player_transform.translation.y;
+            camera_transform.translation.x = player_transform.translation.x + shake.x;
+            camera_transform.translation.y = player_transform.translation.y + shake.y;
         }
     }
 }
 
+pub fn reset_screen_shake(mut screen_shake: ResMut<ScreenShake>) {
+    *screen_shake = ScreenShake::default();
+}
+
 pub fn add_xp(
     mut send_experience_event_reader: EventReader<SendExperienceEvent>,
     mut player_level_up_event_writer: EventWriter<PlayerLevelUpEvent>,
@@ -121,19 +275,178 @@ pub fn add_xp(
     }
 }
 
+pub fn heal_player(
+    mut send_health_event_reader: EventReader<SendHealthEvent>,
+    mut player_query: Query<&mut Player>,
+) {
+    if let Ok(mut player) = player_query.get_single_mut() {
+        for event in &mut send_health_event_reader {
+            player.heal(event.0);
+        }
+    }
+}
+
+pub fn regenerate_health(mut player_query: Query<(&mut Player, &PlayerStats)>, time: Res<Time>) {
+    if let Ok((mut player, stats)) = player_query.get_single_mut() {
+        let regen = stats.get(Stat::Regen) * time.delta_seconds();
+        player.heal(regen);
+    }
+}
+
+/// Copies stats that other components mirror whenever the stats change.
+pub fn apply_stats(
+    mut player_query: Query<(&mut Player, &mut PickupRadius, &PlayerStats), Changed<PlayerStats>>,
+) {
+    if let Ok((mut player, mut pickup_radius, stats)) = player_query.get_single_mut() {
+        player.set_max_health(stats.get(Stat::MaxHealth));
+        pickup_radius.0 = stats.get(Stat::PickupRadius);
+    }
+}
+
+pub fn draw_pickup_radius(
+    mut gizmos: Gizmos,
+    player_query: Query<(&Transform, &PickupRadius), With<Player>>,
+) {
+    if let Ok((player_transform, pickup_radius)) = player_query.get_single() {
+        gizmos.circle_2d(
+            player_transform.translation.truncate(),
+            pickup_radius.0,
+            PICKUP_RADIUS_DEBUG_COLOR,
+        );
+    }
+}
+
+/// Hurts the player by the strongest enemy touching them, less armor, then makes them briefly
+/// invulnerable.
 pub fn player_enemy_collision(
-    mut player_query: Query<(&Transform, &mut Player)>,
-    enemy_query: Query<&Transform, With<Enemy>>,
-    time: Res<Time>,
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &Transform, &mut Player, &PlayerStats), Without<Invulnerable>>,
+    enemy_query: Query<(&Transform, &Enemy, &ContactDamage)>,
+    mut screen_shake: ResMut<ScreenShake>,
+    mut player_hurt_event_writer: EventWriter<PlayerHurtEvent>,
+    mut player_death_event_writer: EventWriter<PlayerDeathEvent>,
 ) {
-    if let Ok((player_transform, mut player)) = player_query.get_single_mut() {
-        for enemy_transform in enemy_query.iter() {
-            let distance = player_transform.translation.distance(enemy_transform.translation);
-            
-            // Adjust this value based on your game's scale and desired collision range
-            if distance < 32.0 {
-                player.take_damage(5.0 * time.delta_seconds());
+    if let Ok((player_entity, player_transform, mut player, stats)) = player_query.get_single_mut() {
+        let hit = enemy_query
+            .iter()
+            .filter(|(enemy_transform, _, contact_damage)| {
+                player_transform
+                    .translation
+                    .truncate()
+                    .distance(enemy_transform.translation.truncate())
+                    < contact_damage.range
+            })
+            .map(|(_, enemy, contact_damage)| (contact_damage.damage, enemy.kind))
+            .reduce(|a, b| if b.0 > a.0 { b } else { a });
+
+        if let Some((damage, kind)) = hit {
+            let damage = (damage - stats.get(Stat::Armor)).max(PLAYER_MIN_DAMAGE_TAKEN);
+            player.take_damage(damage);
+            player_hurt_event_writer.send(PlayerHurtEvent(damage));
+            if player.health.0 <= 0.0 {
+                player_death_event_writer.send(PlayerDeathEvent { killed_by: kind });
             }
+            commands
+                .entity(player_entity)
+                .insert(Invulnerable::new(PLAYER_INVULNERABILITY_DURATION));
+            screen_shake.add_trauma(SCREEN_SHAKE_TRAUMA_PER_HIT);
+        }
+    }
+}
+
+/// Flashes the player while they're invulnerable from being hurt, and ends invulnerability when it runs out.
+pub fn tick_invulnerability(
+    mut commands: Commands,
+    mut player_query: Query<(Entity, &mut Invulnerable, &mut TextureAtlasSprite), With<Player>>,
+    selected_character: Res<SelectedCharacter>,
+    time: Res<Time>,
+) {
+    if let Ok((player_entity, mut invulnerable, mut sprite)) = player_query.get_single_mut() {
+        if invulnerable.timer.tick(time.delta()).finished() {
+            sprite.color = selected_character.color;
+            commands.entity(player_entity).remove::<Invulnerable>();
+        } else if invulnerable.flash {
+            let flashes = (invulnerable.timer.elapsed_secs() / PLAYER_HURT_FLASH_INTERVAL) as u32;
+            let flash = flashes % 2 == 0;
+            sprite.color = if flash { PLAYER_HURT_COLOR } else { selected_character.color };
+        } else {
+            sprite.color = selected_character.color;
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::game::player::DEFAULT_PLAYER_SPEED;
+    use crate::input::gamepad::tests::{app_with_gamepad, set_left_stick};
+
+    use super::*;
+
+    /// A player in the middle of a wrapping world, and a clock that ticks a second per update.
+    fn app_with_player() -> (App, Gamepad, Entity) {
+        let (mut app, gamepad) = app_with_gamepad();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<TextureAtlas>()
+            .insert_resource(InputBindings::default())
+            .insert_resource(GridSettings {
+                mode: WorldMode::Toroidal,
+                ..default()
+            })
+            .add_systems(First, tick_one_second)
+            .add_systems(Update, move_player);
+        let start = Instant::now();
+        let mut time = Time::new(start);
+        time.update_with_instant(start);
+        app.insert_resource(time);
+
+        let center = app.world.resource::<GridSettings>().center();
+        let player = app
+            .world
+            .spawn((
+                Transform::from_translation(center.extend(0.0)),
+                Handle::<TextureAtlas>::default(),
+                Player {
+                    xp: (0.0, 1.0),
+                    lv: 1,
+                    health: (1.0, 1.0),
+                    direction: Vec3::ZERO,
+                },
+                PlayerStats::default(),
+            ))
+            .id();
+        (app, gamepad, player)
+    }
+
+    fn tick_one_second(mut time: ResMut<Time>) {
+        let last_update = time.last_update().unwrap();
+        time.update_with_instant(last_update + Duration::from_secs(1));
+    }
+
+    fn moved_by(app: &mut App, player: Entity) -> Vec2 {
+        let before = app.world.get::<Transform>(player).unwrap().translation;
+        app.update();
+        (app.world.get::<Transform>(player).unwrap().translation - before).truncate()
+    }
+
+    #[test]
+    fn light_stick_push_walks_slower() {
+        let (mut app, gamepad, player) = app_with_player();
+
+        set_left_stick(&mut app, gamepad, Vec2::new(0.6, 0.0));
+        let moved = moved_by(&mut app, player);
+        let expected = Vec2::new(0.5 * DEFAULT_PLAYER_SPEED, 0.0);
+        assert!((moved - expected).length() < 1e-2, "{moved}");
+    }
+
+    #[test]
+    fn full_stick_push_walks_at_full_speed() {
+        let (mut app, gamepad, player) = app_with_player();
+
+        set_left_stick(&mut app, gamepad, Vec2::new(0.0, -1.0));
+        let moved = moved_by(&mut app, player);
+        let expected = Vec2::new(0.0, -DEFAULT_PLAYER_SPEED);
+        assert!((moved - expected).length() < 1e-2, "{moved}");
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use serde::{Deserialize, Serialize};
+
+/// The randomness behind everything that affects a run, seeded per run so its state can be
+/// saved and resumed exactly.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+pub struct GameRng {
+    pub seed: u64,
+    pub rng: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        GameRng {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        GameRng::from_seed(rand::random())
+    }
+}
+
+/// Level-ups and chests waiting for their screen. Only one screen can be open at a time, so
+/// they're shown in turn, level-ups first, each time the game goes back to running.
+#[derive(Resource, Debug, Default)]
+pub struct PendingMenus {
+    pub level_ups: usize,
+    pub chests: usize,
+}
@@ -8,4 +8,6 @@ pub enum GameState {
     Paused,
     Running,
     LevelUp,
+    Chest,
+    Settings,
 }
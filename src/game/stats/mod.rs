@@ -0,0 +1,33 @@
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{
+    resources::RunStats,
+    systems::{
+        record_damage_dealt, record_damage_taken, record_kills, reset_run_stats, tick_run_time,
+    },
+};
+
+use super::states::GameState;
+
+/// Tracks per-run statistics from gameplay events.
+///
+/// `RunStats` is reset whenever a new game is entered and only updated while the game is
+/// running.
+pub struct StatsPlugin;
+
+impl Plugin for StatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunStats>()
+            .add_systems(OnEnter(AppState::Game), reset_run_stats)
+            .add_systems(
+                Update,
+                (record_kills, record_damage_dealt, record_damage_taken, tick_run_time)
+                    .run_if(in_state(GameState::Running)),
+            );
+    }
+}
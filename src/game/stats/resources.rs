@@ -0,0 +1,22 @@
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::game::{
+    enemies::components::EnemyKind, player::abilities::components::DamageSource,
+};
+
+/// Statistics for the current run. Fields missing from a suspended run default, so runs
+/// suspended before a stat was added can still be continued.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RunStats {
+    pub kills: usize,
+    pub elite_kills: usize,
+    pub boss_kills: usize,
+    pub kills_by_kind: HashMap<EnemyKind, usize>,
+    pub kills_by_source: HashMap<DamageSource, usize>,
+    pub damage_by_source: HashMap<DamageSource, f32>,
+    /// Seconds spent playing, not counting pauses and menus.
+    pub time: f32,
+    pub damage_taken: f32,
+}
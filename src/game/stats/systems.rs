@@ -0,0 +1,54 @@
+use bevy::prelude::*;
+
+use crate::game::{
+    enemies::events::EnemyDeathEvent,
+    player::{abilities::events::TransmitDamage, events::PlayerHurtEvent},
+};
+
+use super::resources::RunStats;
+
+pub fn reset_run_stats(mut run_stats: ResMut<RunStats>) {
+    *run_stats = RunStats::default();
+}
+
+pub fn record_kills(
+    mut enemy_death_event_reader: EventReader<EnemyDeathEvent>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in &mut enemy_death_event_reader {
+        debug!("{:?} killed by {:?}", event.entity, event.source);
+        run_stats.kills += 1;
+        if event.elite {
+            run_stats.elite_kills += 1;
+        }
+        if event.boss {
+            run_stats.boss_kills += 1;
+        }
+        *run_stats.kills_by_kind.entry(event.kind).or_default() += 1;
+        if let Some(source) = event.source {
+            *run_stats.kills_by_source.entry(source).or_default() += 1;
+        }
+    }
+}
+
+pub fn tick_run_time(mut run_stats: ResMut<RunStats>, time: Res<Time>) {
+    run_stats.time += time.delta_seconds();
+}
+
+pub fn record_damage_taken(
+    mut player_hurt_event_reader: EventReader<PlayerHurtEvent>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in &mut player_hurt_event_reader {
+        run_stats.damage_taken += event.0;
+    }
+}
+
+pub fn record_damage_dealt(
+    mut transmit_damage_event_reader: EventReader<TransmitDamage>,
+    mut run_stats: ResMut<RunStats>,
+) {
+    for event in &mut transmit_damage_event_reader {
+        *run_stats.damage_by_source.entry(event.source).or_default() += event.damage;
+    }
+}
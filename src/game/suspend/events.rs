@@ -0,0 +1,5 @@
+use bevy::prelude::Event;
+
+/// Saves the run so it can be continued later, then returns to the main menu.
+#[derive(Event)]
+pub struct SuspendRunEvent;
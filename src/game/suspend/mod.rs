@@ -0,0 +1,33 @@
+pub mod events;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{
+    events::SuspendRunEvent,
+    resources::PendingResume,
+    systems::{resume_run, suspend_run},
+};
+
+/// The suspended run, kept beside the save file until it's continued.
+pub const RUN_FILE: &str = "run.json";
+
+/// Saves the run to disk on `SuspendRunEvent` and leaves it. Continuing from the main menu
+/// inserts `PendingResume`, which is applied over the fresh game once it has been set up.
+pub struct SuspendPlugin;
+
+impl Plugin for SuspendPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SuspendRunEvent>().add_systems(
+            Update,
+            (
+                suspend_run,
+                resume_run.run_if(resource_exists::<PendingResume>()),
+            )
+                .run_if(in_state(AppState::Game)),
+        );
+    }
+}
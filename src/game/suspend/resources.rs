@@ -0,0 +1,125 @@
+use std::{fs, io};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config,
+    game::{
+        drops::tables::DropKind,
+        enemies::components::{ContactDamage, Enemy},
+        grid::resources::WorldMode,
+        inventory::components::Inventory,
+        player::{
+            abilities::{
+                components::{Ability, Projectile},
+                resources::WeaponCooldowns,
+            },
+            characters::Character,
+            components::{Dash, Dashing, Invulnerable, Player},
+            stats::PlayerStats,
+        },
+        resources::GameRng,
+        stats::resources::RunStats,
+    },
+};
+
+use super::RUN_FILE;
+
+/// Everything needed to pick a run back up exactly where it was left.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunSnapshot {
+    pub world_mode: WorldMode,
+    pub character: Character,
+    pub rng: GameRng,
+    pub player: PlayerSnapshot,
+    pub enemies: Vec<EnemySnapshot>,
+    pub drops: Vec<DropSnapshot>,
+    pub projectiles: Vec<ProjectileSnapshot>,
+    pub weapon_cooldowns: WeaponCooldowns,
+    pub run_stats: RunStats,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub translation: Vec3,
+    pub player: Player,
+    pub stats: PlayerStats,
+    pub inventory: Inventory,
+    pub dash: Dash,
+    pub dashing: Option<Dashing>,
+    pub invulnerable: Option<Invulnerable>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnemySnapshot {
+    pub enemy: Enemy,
+    pub health: f32,
+    pub contact_damage: ContactDamage,
+    pub translation: Vec3,
+    pub scale: Vec3,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DropSnapshot {
+    pub kind: DropKind,
+    pub translation: Vec3,
+    /// What an experience gem is worth, which grows as gems merge.
+    pub value: Option<f32>,
+    /// The speed of a drop being pulled in by a magnet.
+    pub magnetized: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectileSnapshot {
+    pub projectile: Projectile,
+    pub ability: Ability,
+    pub translation: Vec3,
+    pub scale: Vec3,
+    /// For piercing projectiles, the enemies already hit, as indices into `enemies`.
+    pub piercing: Option<Vec<usize>>,
+}
+
+impl RunSnapshot {
+    /// Whether there's a suspended run to continue.
+    pub fn exists() -> bool {
+        config::data_path(RUN_FILE).is_some_and(|path| path.exists())
+    }
+
+    pub fn save(&self) {
+        let Some(path) = config::data_path(RUN_FILE) else {
+            warn!("Couldn't save the run: no data directory");
+            return;
+        };
+        let result = serde_json::to_string(self)
+            .map_err(io::Error::from)
+            .and_then(|contents| config::write_atomic(&path, &contents));
+        if let Err(error) = result {
+            warn!("Couldn't save the run to {}: {}", path.display(), error);
+        }
+    }
+
+    /// Reads the suspended run and deletes it, so a run can only be continued once. An
+    /// unreadable run is deleted too, as there's nothing to continue.
+    pub fn take() -> Option<Self> {
+        let path = config::data_path(RUN_FILE)?;
+        let result = fs::read_to_string(&path)
+            .map_err(|error| error.to_string())
+            .and_then(|contents| serde_json::from_str(&contents).map_err(|error| error.to_string()));
+        if let Err(error) = fs::remove_file(&path) {
+            warn!("Couldn't delete the suspended run {}: {}", path.display(), error);
+        }
+
+        match result {
+            Ok(snapshot) => Some(snapshot),
+            Err(error) => {
+                warn!("Suspended run {} is unreadable: {}", path.display(), error);
+                None
+            }
+        }
+    }
+}
+
+/// A run being continued, applied once the game it replaces has been set up.
+#[derive(Resource, Debug, Deref)]
+pub struct PendingResume(pub RunSnapshot);
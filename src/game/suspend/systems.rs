@@ -0,0 +1,486 @@
+use bevy::{ecs::query::Has, prelude::*, utils::HashMap};
+
+use crate::game::{
+    components::Health,
+    drops::{
+        chest::{components::ChestPickup, systems::spawn_chest_pickup},
+        coin::{components::CoinPickup, systems::spawn_coin_pickup},
+        components::{Drop, Magnetized},
+        experience::{
+            components::{Experience, ExperienceValue},
+            resources::ExperienceAtlases,
+            systems::spawn_experience_gem,
+        },
+        health::{components::HealthPickup, systems::spawn_health_pickup},
+        magnet::{components::MagnetPickup, systems::spawn_magnet_pickup},
+        tables::DropKind,
+    },
+    enemies::{
+        components::{ContactDamage, Enemy, EnemyKind},
+        triangle::systems::{load_triangle_texture_atlas, spawn_triangle},
+    },
+    grid::resources::GridSettings,
+    inventory::components::Inventory,
+    player::{
+        abilities::{
+            components::{Ability, Piercing, Projectile},
+            dot::{components::Dot, systems::spawn_dot_projectile},
+            resources::WeaponCooldowns,
+        },
+        components::{Dash, Dashing, Invulnerable, Player},
+        resources::SelectedCharacter,
+        stats::PlayerStats,
+    },
+    resources::GameRng,
+    states::GameState,
+    stats::resources::RunStats,
+};
+
+use super::{
+    events::SuspendRunEvent,
+    resources::{
+        DropSnapshot, EnemySnapshot, PendingResume, PlayerSnapshot, ProjectileSnapshot,
+        RunSnapshot,
+    },
+};
+
+/// Writes the run to disk and leaves it, so it can be continued from the main menu.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn suspend_run(
+    mut suspend_run_event_reader: EventReader<SuspendRunEvent>,
+    player_query: Query<
+        (
+            &Transform,
+            &Player,
+            &PlayerStats,
+            &Inventory,
+            &Dash,
+            Option<&Dashing>,
+            Option<&Invulnerable>,
+        ),
+        With<Player>,
+    >,
+    enemy_query: Query<(Entity, &Transform, &Enemy, &Health, &ContactDamage)>,
+    drop_query: Query<
+        (
+            &Transform,
+            Option<&Experience>,
+            Option<&ExperienceValue>,
+            Has<HealthPickup>,
+            Has<MagnetPickup>,
+            Has<ChestPickup>,
+            Has<CoinPickup>,
+            Option<&Magnetized>,
+        ),
+        With<Drop>,
+    >,
+    projectile_query: Query<(&Transform, &Projectile, &Ability, Option<&Piercing>), With<Dot>>,
+    grid_settings: Res<GridSettings>,
+    selected_character: Res<SelectedCharacter>,
+    game_rng: Res<GameRng>,
+    weapon_cooldowns: Res<WeaponCooldowns>,
+    run_stats: Res<RunStats>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    if suspend_run_event_reader.is_empty() {
+        return;
+    }
+    suspend_run_event_reader.clear();
+
+    let Ok((transform, player, stats, inventory, dash, dashing, invulnerable)) =
+        player_query.get_single()
+    else {
+        return;
+    };
+
+    let mut enemy_indices = HashMap::new();
+    let enemies = enemy_query
+        .iter()
+        .enumerate()
+        .map(|(index, (entity, transform, enemy, health, contact_damage))| {
+            enemy_indices.insert(entity, index);
+            EnemySnapshot {
+                enemy: enemy.clone(),
+                health: health.0,
+                contact_damage: contact_damage.clone(),
+                translation: transform.translation,
+                scale: transform.scale,
+            }
+        })
+        .collect();
+
+    let drops = drop_query
+        .iter()
+        .filter_map(|(transform, experience, value, health, magnet, chest, coin, magnetized)| {
+            let kind = match (experience, health, magnet, chest, coin) {
+                (Some(experience), ..) => DropKind::Experience(*experience),
+                (_, true, ..) => DropKind::Health,
+                (_, _, true, ..) => DropKind::Magnet,
+                (_, _, _, true, _) => DropKind::Chest,
+                (_, _, _, _, true) => DropKind::Coin,
+                _ => return None,
+            };
+            Some(DropSnapshot {
+                kind,
+                translation: transform.translation,
+                value: value.map(|value| value.0),
+                magnetized: magnetized.map(|magnetized| magnetized.speed),
+            })
+        })
+        .collect();
+
+    let projectiles = projectile_query
+        .iter()
+        .map(|(transform, projectile, ability, piercing)| ProjectileSnapshot {
+            projectile: projectile.clone(),
+            ability: ability.clone(),
+            translation: transform.translation,
+            scale: transform.scale,
+            // Enemies that have since died can't be hit again anyway.
+            piercing: piercing.map(|piercing| {
+                piercing
+                    .hit
+                    .iter()
+                    .filter_map(|entity| enemy_indices.get(entity).copied())
+                    .collect()
+            }),
+        })
+        .collect();
+
+    RunSnapshot {
+        world_mode: grid_settings.mode,
+        character: selected_character.0.clone(),
+        rng: game_rng.clone(),
+        player: PlayerSnapshot {
+            translation: transform.translation,
+            player: player.clone(),
+            stats: stats.clone(),
+            inventory: inventory.clone(),
+            dash: dash.clone(),
+            dashing: dashing.cloned(),
+            invulnerable: invulnerable.cloned(),
+        },
+        enemies,
+        drops,
+        projectiles,
+        weapon_cooldowns: weapon_cooldowns.clone(),
+        run_stats: run_stats.clone(),
+    }
+    .save();
+
+    next_game_state.set(GameState::Inactive);
+}
+
+/// Puts a continued run back the way it was saved, over the fresh game set up on entering
+/// `AppState::Game`, and leaves it paused so the player can get their bearings.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn resume_run(
+    mut commands: Commands,
+    pending_resume: Res<PendingResume>,
+    mut player_query: Query<
+        (
+            Entity,
+            &mut Transform,
+            &mut Player,
+            &mut PlayerStats,
+            &mut Inventory,
+            &mut Dash,
+        ),
+        With<Player>,
+    >,
+    asset_server: Res<AssetServer>,
+    mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    experience_atlases: Res<ExperienceAtlases>,
+    mut game_rng: ResMut<GameRng>,
+    mut weapon_cooldowns: ResMut<WeaponCooldowns>,
+    mut run_stats: ResMut<RunStats>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+) {
+    // The player is spawned by commands on entering the game, so may not exist quite yet.
+    let Ok((player_entity, mut transform, mut player, mut stats, mut inventory, mut dash)) =
+        player_query.get_single_mut()
+    else {
+        return;
+    };
+    let snapshot = &pending_resume.0;
+
+    transform.translation = snapshot.player.translation;
+    *player = snapshot.player.player.clone();
+    *stats = snapshot.player.stats.clone();
+    *inventory = snapshot.player.inventory.clone();
+    *dash = snapshot.player.dash.clone();
+    if let Some(dashing) = &snapshot.player.dashing {
+        commands.entity(player_entity).insert(dashing.clone());
+    }
+    if let Some(invulnerable) = &snapshot.player.invulnerable {
+        commands.entity(player_entity).insert(invulnerable.clone());
+    }
+
+    let triangle_texture_atlas = load_triangle_texture_atlas(&asset_server, &mut texture_atlases);
+    let mut enemy_entities = Vec::with_capacity(snapshot.enemies.len());
+    for enemy in &snapshot.enemies {
+        let entity = match enemy.enemy.kind {
+            EnemyKind::Triangle => spawn_triangle(
+                &mut commands,
+                triangle_texture_atlas.clone(),
+                enemy.translation,
+                enemy.scale,
+            ),
+        };
+        commands.entity(entity).insert((
+            enemy.enemy.clone(),
+            Health(enemy.health),
+            enemy.contact_damage.clone(),
+        ));
+        enemy_entities.push(entity);
+    }
+
+    for drop in &snapshot.drops {
+        let entity = match drop.kind {
+            DropKind::Experience(xp) => spawn_experience_gem(
+                &mut commands,
+                &experience_atlases,
+                xp,
+                drop.value.unwrap_or(xp.into()),
+                drop.translation,
+            ),
+            DropKind::Health => {
+                spawn_health_pickup(&mut commands, &mut meshes, &mut materials, drop.translation)
+            }
+            DropKind::Magnet => {
+                spawn_magnet_pickup(&mut commands, &mut meshes, &mut materials, drop.translation)
+            }
+            DropKind::Chest => {
+                spawn_chest_pickup(&mut commands, &mut meshes, &mut materials, drop.translation)
+            }
+            DropKind::Coin => {
+                spawn_coin_pickup(&mut commands, &mut meshes, &mut materials, drop.translation)
+            }
+        };
+        if let Some(speed) = drop.magnetized {
+            commands.entity(entity).insert(Magnetized { speed });
+        }
+    }
+
+    for projectile in &snapshot.projectiles {
+        let entity = spawn_dot_projectile(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            projectile.translation,
+            projectile.scale,
+            projectile.projectile.clone(),
+            projectile.ability.clone(),
+        );
+        if let Some(hit) = &projectile.piercing {
+            let hit = hit
+                .iter()
+                .filter_map(|index| enemy_entities.get(*index).copied())
+                .collect();
+            commands.entity(entity).insert(Piercing { hit });
+        }
+    }
+
+    *game_rng = snapshot.rng.clone();
+    *weapon_cooldowns = snapshot.weapon_cooldowns.clone();
+    *run_stats = snapshot.run_stats.clone();
+
+    commands.remove_resource::<PendingResume>();
+    next_game_state.set(GameState::Paused);
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::utils::HashMap;
+    use rand::Rng;
+
+    use crate::game::{
+        drops::experience::components::Experience,
+        inventory::items::Weapon,
+        player::{abilities::components::DamageSource, characters::Character},
+    };
+
+    use super::*;
+
+    /// A game that's just been set up, with a fresh player and nothing else yet.
+    fn app_with_player() -> App {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+            .add_asset::<Mesh>()
+            .add_asset::<ColorMaterial>()
+            .add_asset::<TextureAtlas>()
+            .add_state::<GameState>()
+            .add_event::<SuspendRunEvent>()
+            .insert_resource(GridSettings::default())
+            .insert_resource(SelectedCharacter(Character {
+                id: "square".to_string(),
+                name: "Square".to_string(),
+                description: String::new(),
+                sprite: String::new(),
+                color: Color::WHITE,
+                weapon: Weapon::Dot,
+                base_stats: HashMap::new(),
+                bonus: None,
+                locked: false,
+            }))
+            .insert_resource(GameRng::from_seed(7))
+            .insert_resource(ExperienceAtlases {
+                x: default(),
+                y: default(),
+                z: default(),
+            })
+            .init_resource::<WeaponCooldowns>()
+            .init_resource::<RunStats>();
+        app.world.spawn((
+            Transform::default(),
+            Player {
+                xp: (0.0, 10.0),
+                lv: 1,
+                health: (100.0, 100.0),
+                direction: Vec3::ZERO,
+            },
+            PlayerStats::default(),
+            Inventory::with_weapon(Weapon::Dot),
+            Dash::default(),
+        ));
+        app
+    }
+
+    fn run<M>(app: &mut App, systems: impl IntoSystemConfigs<M>) {
+        let mut schedule = Schedule::new();
+        schedule.add_systems(systems);
+        schedule.run(&mut app.world);
+    }
+
+    /// Plays a bit of a run: moves the player, and fills the world with enemies, drops and
+    /// projectiles, one of which has already pierced an enemy.
+    #[allow(clippy::too_many_arguments)]
+    fn play(
+        mut commands: Commands,
+        mut player_query: Query<(&mut Transform, &mut Player, &mut Inventory, &mut Dash)>,
+        asset_server: Res<AssetServer>,
+        mut texture_atlases: ResMut<Assets<TextureAtlas>>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<ColorMaterial>>,
+        experience_atlases: Res<ExperienceAtlases>,
+        mut game_rng: ResMut<GameRng>,
+        mut weapon_cooldowns: ResMut<WeaponCooldowns>,
+        mut run_stats: ResMut<RunStats>,
+    ) {
+        let (mut transform, mut player, mut inventory, mut dash) = player_query.single_mut();
+        transform.translation = Vec3::new(120.0, 80.0, 0.0);
+        player.level_up();
+        player.health.0 = 64.0;
+        inventory.rank_up_weapon(Weapon::Dot);
+        dash.cooldown.reset();
+
+        let atlas = load_triangle_texture_atlas(&asset_server, &mut texture_atlases);
+        let enemy = spawn_triangle(
+            &mut commands,
+            atlas.clone(),
+            Vec3::new(300.0, 80.0, 0.0),
+            Vec3::ONE,
+        );
+        commands.entity(enemy).insert(Health(12.5));
+        let pierced = spawn_triangle(
+            &mut commands,
+            atlas,
+            Vec3::new(-90.0, 10.0, 0.0),
+            Vec3::splat(2.0),
+        );
+
+        let gem = spawn_experience_gem(
+            &mut commands,
+            &experience_atlases,
+            Experience::Y,
+            7.0,
+            Vec3::new(5.0, 5.0, 0.0),
+        );
+        commands.entity(gem).insert(Magnetized { speed: 90.0 });
+        spawn_health_pickup(&mut commands, &mut meshes, &mut materials, Vec3::new(6.0, 6.0, 0.0));
+        spawn_magnet_pickup(&mut commands, &mut meshes, &mut materials, Vec3::new(7.0, 7.0, 0.0));
+
+        spawn_dot_projectile(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            Vec3::new(140.0, 80.0, -1.0),
+            Vec3::new(2.0, 2.0, 1.0),
+            Projectile {
+                speed: 500.0,
+                direction: Vec3::new(-1.0, 0.0, 0.0),
+                travelled: 20.0,
+            },
+            Ability {
+                damage: 125.0,
+                source: DamageSource::Dot,
+            },
+        );
+        let burst = spawn_dot_projectile(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            Vec3::new(100.0, 60.0, -1.0),
+            Vec3::new(2.0, 2.0, 1.0),
+            Projectile {
+                speed: 500.0,
+                direction: Vec3::new(0.0, 1.0, 0.0),
+                travelled: 30.0,
+            },
+            Ability {
+                damage: 150.0,
+                source: DamageSource::DotBurst,
+            },
+        );
+        commands.entity(burst).insert(Piercing { hit: vec![pierced] });
+
+        game_rng.rng.gen::<u64>();
+        weapon_cooldowns.fired(Weapon::Dot);
+        weapon_cooldowns.0.insert(Weapon::DotBurst, 0.75);
+        run_stats.kills = 3;
+    }
+
+    fn suspend(app: &mut App) -> RunSnapshot {
+        app.world.send_event(SuspendRunEvent);
+        run(app, suspend_run);
+        RunSnapshot::take().expect("the run should have been saved")
+    }
+
+    #[test]
+    fn suspended_runs_resume_as_they_were() {
+        let mut app = app_with_player();
+        run(&mut app, play);
+        let snapshot = suspend(&mut app);
+        assert!(RunSnapshot::take().is_none(), "a run can only be continued once");
+
+        assert_eq!(snapshot.player.translation, Vec3::new(120.0, 80.0, 0.0));
+        assert_eq!(snapshot.enemies.len(), 2);
+        assert_eq!(snapshot.drops.len(), 3);
+        assert_eq!(snapshot.projectiles.len(), 2);
+        let pierced_index = snapshot
+            .enemies
+            .iter()
+            .position(|enemy| enemy.translation == Vec3::new(-90.0, 10.0, 0.0))
+            .unwrap();
+        let piercing: Vec<_> = snapshot
+            .projectiles
+            .iter()
+            .filter_map(|projectile| projectile.piercing.clone())
+            .collect();
+        assert_eq!(piercing, [vec![pierced_index]]);
+
+        let mut resumed = app_with_player();
+        resumed.insert_resource(PendingResume(snapshot.clone()));
+        run(&mut resumed, resume_run);
+        assert!(!resumed.world.contains_resource::<PendingResume>());
+
+        assert_eq!(
+            serde_json::to_value(suspend(&mut resumed)).unwrap(),
+            serde_json::to_value(&snapshot).unwrap()
+        );
+        let next_roll = |app: &mut App| app.world.resource_mut::<GameRng>().rng.gen::<u64>();
+        assert_eq!(next_roll(&mut resumed), next_roll(&mut app));
+    }
+}
@@ -1,22 +1,43 @@
 use bevy::prelude::*;
 
-use super::{states::GameState, components::{AnimationIndices, AnimationTimer}, player::events::PlayerLevelUpEvent};
+use crate::input::{actions::Action, resources::InputBindings};
+
+use super::{
+    components::{AnimationIndices, AnimationTimer},
+    drops::chest::events::OpenChestEvent,
+    grid::resources::{GridSettings, WorldMode},
+    player::events::PlayerLevelUpEvent,
+    resources::{GameRng, PendingMenus},
+    states::GameState,
+};
 
 pub fn toggle_game_state(
     keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    input_bindings: Res<InputBindings>,
     current_game_state: Res<State<GameState>>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::P) {
+    if input_bindings.just_pressed_any(Action::Pause, &keyboard_input, &gamepad_buttons) {
         match *current_game_state.get() {
+            GameState::Chest | GameState::Settings => {}
             GameState::Running | GameState::Inactive => next_game_state.set(GameState::Paused),
             _ => next_game_state.set(GameState::Running),
         }
     }
 }
 
-pub fn new_game(mut next_game_state: ResMut<NextState<GameState>>) {
+/// Gives every run a fresh seed.
+pub fn seed_game_rng(mut game_rng: ResMut<GameRng>) {
+    *game_rng = GameRng::default();
+}
+
+pub fn new_game(
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut pending_menus: ResMut<PendingMenus>,
+) {
     next_game_state.set(GameState::NewGame);
+    *pending_menus = PendingMenus::default();
 }
 
 pub fn deactivate_game(mut next_game_state: ResMut<NextState<GameState>>) {
@@ -42,9 +63,76 @@ pub fn animate_sprites(
 
 pub fn handle_player_level_up(
     mut player_level_up_event_reader: EventReader<PlayerLevelUpEvent>,
+    mut pending_menus: ResMut<PendingMenus>,
+) {
+    pending_menus.level_ups += player_level_up_event_reader.len();
+    player_level_up_event_reader.clear();
+}
+
+pub fn handle_open_chest(
+    mut open_chest_event_reader: EventReader<OpenChestEvent>,
+    mut pending_menus: ResMut<PendingMenus>,
+) {
+    pending_menus.chests += open_chest_event_reader.len();
+    open_chest_event_reader.clear();
+}
+
+/// Opens the next waiting level-up or chest screen. The rest stay queued until the game is
+/// running again.
+pub fn open_pending_menu(
+    mut pending_menus: ResMut<PendingMenus>,
     mut next_game_state: ResMut<NextState<GameState>>,
 ) {
-    for _event in player_level_up_event_reader.iter() {
+    if pending_menus.level_ups > 0 {
+        pending_menus.level_ups -= 1;
         next_game_state.set(GameState::LevelUp);
+    } else if pending_menus.chests > 0 {
+        pending_menus.chests -= 1;
+        next_game_state.set(GameState::Chest);
     }
-}
\ No newline at end of file
+}
+
+pub fn toroidal_world(grid_settings: Res<GridSettings>) -> bool {
+    grid_settings.mode == WorldMode::Toroidal
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::gamepad::tests::{app_with_gamepad, set_button};
+
+    use super::*;
+
+    fn app_in_game_state(game_state: GameState) -> (App, Gamepad) {
+        let (mut app, gamepad) = app_with_gamepad();
+        app.add_state::<GameState>()
+            .insert_resource(InputBindings::default())
+            .add_systems(Update, toggle_game_state);
+        app.world.insert_resource(NextState(Some(game_state)));
+        app.update();
+        (app, gamepad)
+    }
+
+    /// Taps Start, then updates once more so the state change is applied.
+    fn tap_start(app: &mut App, gamepad: Gamepad) -> GameState {
+        set_button(app, gamepad, GamepadButtonType::Start, true);
+        app.update();
+        set_button(app, gamepad, GamepadButtonType::Start, false);
+        app.update();
+        *app.world.resource::<State<GameState>>().get()
+    }
+
+    #[test]
+    fn start_toggles_pause() {
+        let (mut app, gamepad) = app_in_game_state(GameState::Running);
+
+        assert_eq!(tap_start(&mut app, gamepad), GameState::Paused);
+        assert_eq!(tap_start(&mut app, gamepad), GameState::Running);
+    }
+
+    #[test]
+    fn start_leaves_the_chest_menu_alone() {
+        let (mut app, gamepad) = app_in_game_state(GameState::Chest);
+
+        assert_eq!(tap_start(&mut app, gamepad), GameState::Chest);
+    }
+}
@@ -20,3 +20,12 @@ pub struct HealthBarContainer;
 
 #[derive(Component)]
 pub struct HealthBarText;
+
+#[derive(Component)]
+pub struct KillCounterText;
+
+#[derive(Component)]
+pub struct InventoryText;
+
+#[derive(Component)]
+pub struct DashBar;
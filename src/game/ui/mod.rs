@@ -6,9 +6,12 @@ use bevy::prelude::*;
 
 use crate::states::AppState;
 
-use self::systems::{animate_xp_bar, animate_health_bar, despawn_ui, spawn_ui, update_xp_text};
+use self::systems::{
+    animate_dash_bar, animate_health_bar, animate_xp_bar, despawn_ui, spawn_ui, update_health_text,
+    update_inventory_text, update_kill_counter, update_xp_text,
+};
 
-use super::states::GameState;
+use super::{states::GameState, stats::resources::RunStats};
 
 pub struct UIPlugin;
 
@@ -17,8 +20,17 @@ impl Plugin for UIPlugin {
         app.add_systems(OnEnter(AppState::Game), spawn_ui)
             .add_systems(
                 Update,
-                (animate_health_bar, animate_xp_bar, update_xp_text).run_if(in_state(GameState::Running)),
+                (
+                    animate_health_bar,
+                    update_health_text,
+                    animate_dash_bar,
+                    animate_xp_bar,
+                    update_xp_text,
+                    update_kill_counter.run_if(resource_changed::<RunStats>()),
+                )
+                    .run_if(in_state(GameState::Running)),
             )
+            .add_systems(Update, update_inventory_text.run_if(in_state(AppState::Game)))
             .add_systems(OnExit(AppState::Game), despawn_ui);
     }
 }
@@ -60,6 +60,22 @@ pub const FILLING_BAR_TEXT_STYLE: Style = {
     style
 };
 
+pub const KILL_COUNTER_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.position_type = PositionType::Absolute;
+    style.top = Val::Percent(1.0);
+    style.right = Val::Percent(1.0);
+    style
+};
+
+pub const INVENTORY_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.position_type = PositionType::Absolute;
+    style.top = Val::Percent(1.0);
+    style.left = Val::Percent(1.0);
+    style
+};
+
 pub fn get_filling_bar_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
     Text {
         sections: vec![TextSection::new(
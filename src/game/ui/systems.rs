@@ -1,12 +1,22 @@
 use bevy::prelude::*;
 
-use crate::game::player::{components::Player, events::PlayerLevelUpEvent};
+use crate::game::{
+    inventory::{components::Inventory, PASSIVE_SLOTS, WEAPON_SLOTS},
+    player::{
+        components::{Dash, Player},
+        events::PlayerLevelUpEvent,
+    },
+    stats::resources::RunStats,
+};
 
 use super::{
-    components::{HealthBar, HealthBarContainer, HealthBarText, XPBar, XPBarContainer, XPText, UI},
+    components::{
+        DashBar, HealthBar, HealthBarContainer, HealthBarText, InventoryText, KillCounterText, XPBar, XPBarContainer,
+        XPText, UI,
+    },
     styles::{
         get_filling_bar_text, FILLING_BAR_CONTAINER_STYLE, FILLING_BAR_CONTAINER_STYLE_BOTTOM,
-        FILLING_BAR_STYLE, FILLING_BAR_TEXT_STYLE, UI_STYLE,
+        FILLING_BAR_STYLE, FILLING_BAR_TEXT_STYLE, INVENTORY_STYLE, KILL_COUNTER_STYLE, UI_STYLE,
     },
 };
 
@@ -22,6 +32,9 @@ pub fn spawn_ui(mut commands: Commands, asset_server: Res<AssetServer>) {
         .with_children(|parent| {
             spawn_xp_bar(parent, &asset_server);
             spawn_health_bar(parent, &asset_server);
+            spawn_dash_bar(parent, &asset_server);
+            spawn_kill_counter(parent, &asset_server);
+            spawn_inventory_text(parent, &asset_server);
         });
 }
 
@@ -130,8 +143,114 @@ pub fn animate_health_bar(
 ) {
     if let Ok(player) = player_query.get_single() {
         if let Ok(mut health_bar_style) = health_bar_query.get_single_mut() {
-            let health_percent = player.health.0 / player.health.1 * 100.0;
+            let health_percent = (player.health.0 / player.health.1 * 100.0).clamp(0.0, 100.0);
             health_bar_style.width = Val::Percent(health_percent);
         }
     }
 }
+
+pub fn update_health_text(
+    mut health_text_query: Query<&mut Text, With<HealthBarText>>,
+    player_query: Query<&Player, Changed<Player>>,
+) {
+    if let Ok(player) = player_query.get_single() {
+        if let Ok(mut health_text) = health_text_query.get_single_mut() {
+            health_text.sections[0].value =
+                format!("HP: {:.0}/{:.0}", player.health.0.ceil(), player.health.1);
+        }
+    }
+}
+
+fn spawn_dash_bar(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+    parent
+        .spawn(NodeBundle {
+            style: FILLING_BAR_CONTAINER_STYLE,
+            background_color: Color::hsla(0.0, 0.0, 1.0, 0.5).into(),
+            border_color: Color::BLACK.into(),
+            ..default()
+        })
+        .with_children(|container| {
+            container.spawn(TextBundle {
+                text: get_filling_bar_text(asset_server, "Dash"),
+                style: FILLING_BAR_TEXT_STYLE,
+                ..default()
+            });
+            container.spawn((
+                NodeBundle {
+                    style: FILLING_BAR_STYLE,
+                    background_color: Color::YELLOW.into(),
+                    ..default()
+                },
+                DashBar {},
+            ));
+        });
+}
+
+/// Fills up as the dash comes off cooldown.
+pub fn animate_dash_bar(
+    mut dash_bar_query: Query<&mut Style, With<DashBar>>,
+    player_query: Query<&Dash, With<Player>>,
+) {
+    if let Ok(dash) = player_query.get_single() {
+        if let Ok(mut dash_bar_style) = dash_bar_query.get_single_mut() {
+            dash_bar_style.width = Val::Percent(dash.cooldown.percent() * 100.0);
+        }
+    }
+}
+
+fn spawn_kill_counter(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+    parent.spawn((
+        TextBundle {
+            text: get_filling_bar_text(asset_server, "Kills: 0"),
+            style: KILL_COUNTER_STYLE,
+            ..default()
+        },
+        KillCounterText {},
+    ));
+}
+
+pub fn update_kill_counter(
+    mut kill_counter_text_query: Query<&mut Text, With<KillCounterText>>,
+    run_stats: Res<RunStats>,
+) {
+    if let Ok(mut kill_counter_text) = kill_counter_text_query.get_single_mut() {
+        kill_counter_text.sections[0].value = format!("Kills: {}", run_stats.kills);
+    }
+}
+
+fn spawn_inventory_text(parent: &mut ChildBuilder, asset_server: &Res<AssetServer>) {
+    parent.spawn((
+        TextBundle {
+            text: get_filling_bar_text(asset_server, "").with_alignment(TextAlignment::Left),
+            style: INVENTORY_STYLE,
+            ..default()
+        },
+        InventoryText {},
+    ));
+}
+
+/// Lists owned weapons and passives with their ranks, and how many slots are in use.
+pub fn update_inventory_text(
+    mut inventory_text_query: Query<&mut Text, With<InventoryText>>,
+    inventory_query: Query<&Inventory, Changed<Inventory>>,
+) {
+    if let Ok(inventory) = inventory_query.get_single() {
+        if let Ok(mut inventory_text) = inventory_text_query.get_single_mut() {
+            let mut lines = vec![format!("Weapons {}/{}", inventory.weapons.len(), WEAPON_SLOTS)];
+            lines.extend(
+                inventory
+                    .weapons
+                    .iter()
+                    .map(|(weapon, rank)| format!("  {} {}/{}", weapon, rank, weapon.max_rank())),
+            );
+            lines.push(format!("Passives {}/{}", inventory.passives.len(), PASSIVE_SLOTS));
+            lines.extend(
+                inventory
+                    .passives
+                    .iter()
+                    .map(|(passive, rank)| format!("  {} {}/{}", passive, rank, passive.max_rank())),
+            );
+            inventory_text.sections[0].value = lines.join("\n");
+        }
+    }
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Event;
+
+use super::pool::Upgrade;
+
+#[derive(Event)]
+pub struct ApplyUpgradeEvent(pub Upgrade);
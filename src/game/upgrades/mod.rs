@@ -0,0 +1,24 @@
+pub mod events;
+pub mod pool;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{events::ApplyUpgradeEvent, systems::apply_upgrades};
+
+pub const UPGRADE_MAX_HEALTH_AMOUNT: f32 = 2.0;
+pub const UPGRADE_REGEN_AMOUNT: f32 = 0.1;
+pub const UPGRADE_PICKUP_RADIUS_AMOUNT: f32 = 40.0;
+pub const UPGRADE_ARMOR_AMOUNT: f32 = 0.5;
+
+/// Applies upgrades chosen from menus such as the level-up screen to the player.
+pub struct UpgradesPlugin;
+
+impl Plugin for UpgradesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ApplyUpgradeEvent>()
+            .add_systems(Update, apply_upgrades.run_if(in_state(AppState::Game)));
+    }
+}
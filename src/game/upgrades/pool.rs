@@ -0,0 +1,158 @@
+use std::{collections::BTreeSet, fmt::Display};
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::game::{
+    inventory::{
+        components::Inventory,
+        evolutions::Evolution,
+        items::{Passive, Weapon},
+    },
+    player::stats::{Modifier, ModifierKind, ModifierSource, PlayerStats, Stat},
+};
+
+use super::{
+    UPGRADE_ARMOR_AMOUNT, UPGRADE_MAX_HEALTH_AMOUNT, UPGRADE_PICKUP_RADIUS_AMOUNT,
+    UPGRADE_REGEN_AMOUNT,
+};
+
+/// Every upgrade that can be offered to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Upgrade {
+    MaxHealth,
+    Regen,
+    PickupRadius,
+    Armor,
+    /// Adds the weapon, or ranks it up if it's already owned.
+    Weapon(Weapon),
+    /// Adds the passive, or ranks it up if it's already owned.
+    Passive(Passive),
+    /// Only offered by chests, once the recipe is eligible.
+    Evolution(Evolution),
+}
+
+impl Upgrade {
+    /// Every upgrade except evolutions, which depend on the loaded recipes.
+    pub const ALL: [Upgrade; 9] = [
+        Upgrade::MaxHealth,
+        Upgrade::Regen,
+        Upgrade::PickupRadius,
+        Upgrade::Armor,
+        Upgrade::Weapon(Weapon::Dot),
+        Upgrade::Passive(Passive::Might),
+        Upgrade::Passive(Passive::Haste),
+        Upgrade::Passive(Passive::Swiftness),
+        Upgrade::Passive(Passive::Magnet),
+    ];
+
+    /// The upgrades that would still do something for the given inventory. New items stop
+    /// being offered once their slots are full, and weapons aren't offered until unlocked.
+    pub fn available(inventory: &Inventory, unlocked_weapons: &BTreeSet<Weapon>) -> Vec<Upgrade> {
+        Upgrade::ALL
+            .into_iter()
+            .filter(|upgrade| match upgrade {
+                Upgrade::Weapon(weapon) => {
+                    (unlocked_weapons.contains(weapon) || inventory.weapon_rank(*weapon) > 0)
+                        && inventory.can_rank_up_weapon(*weapon)
+                }
+                Upgrade::Passive(passive) => inventory.can_rank_up_passive(*passive),
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Picks up to `count` different upgrades at random.
+    pub fn roll(
+        count: usize,
+        inventory: &Inventory,
+        unlocked_weapons: &BTreeSet<Weapon>,
+        rng: &mut impl Rng,
+    ) -> Vec<Upgrade> {
+        Upgrade::available(inventory, unlocked_weapons)
+            .choose_multiple(rng, count)
+            .copied()
+            .collect()
+    }
+
+    /// Picks `count` upgrades for a chest, with any eligible evolutions into unlocked weapons
+    /// first. Chests can hold more rewards than there are upgrades, so the same upgrade may come
+    /// up more than once, but only while it would still do something after the rewards before
+    /// it have been applied.
+    pub fn roll_chest(
+        count: usize,
+        inventory: &Inventory,
+        unlocked_weapons: &BTreeSet<Weapon>,
+        evolutions: impl IntoIterator<Item = Evolution>,
+        rng: &mut impl Rng,
+    ) -> Vec<Upgrade> {
+        let mut inventory = inventory.clone();
+        let mut rewards: Vec<Upgrade> = evolutions
+            .into_iter()
+            .filter(|evolution| unlocked_weapons.contains(&evolution.evolves_into))
+            .map(Upgrade::Evolution)
+            .take(count)
+            .collect();
+        for reward in &rewards {
+            reward.apply_to_inventory(&mut inventory);
+        }
+
+        while rewards.len() < count {
+            match Upgrade::available(&inventory, unlocked_weapons).choose(rng) {
+                Some(upgrade) => {
+                    upgrade.apply_to_inventory(&mut inventory);
+                    rewards.push(*upgrade);
+                }
+                None => break,
+            }
+        }
+        rewards
+    }
+
+    pub fn apply(&self, stats: &mut PlayerStats, inventory: &mut Inventory) {
+        let mut add_stat = |stat, amount| {
+            stats.add_modifier(Modifier {
+                stat,
+                source: ModifierSource::Upgrade,
+                kind: ModifierKind::Additive(amount),
+            })
+        };
+
+        match self {
+            Upgrade::MaxHealth => add_stat(Stat::MaxHealth, UPGRADE_MAX_HEALTH_AMOUNT),
+            Upgrade::Regen => add_stat(Stat::Regen, UPGRADE_REGEN_AMOUNT),
+            Upgrade::PickupRadius => add_stat(Stat::PickupRadius, UPGRADE_PICKUP_RADIUS_AMOUNT),
+            Upgrade::Armor => add_stat(Stat::Armor, UPGRADE_ARMOR_AMOUNT),
+            Upgrade::Weapon(_) | Upgrade::Passive(_) | Upgrade::Evolution(_) => {
+                self.apply_to_inventory(inventory)
+            }
+        }
+    }
+
+    /// Applies the upgrade's inventory changes. Stat upgrades don't touch the inventory.
+    fn apply_to_inventory(&self, inventory: &mut Inventory) {
+        match self {
+            Upgrade::Weapon(weapon) => {
+                inventory.rank_up_weapon(*weapon);
+            }
+            Upgrade::Passive(passive) => {
+                inventory.rank_up_passive(*passive);
+            }
+            Upgrade::Evolution(evolution) => inventory.evolve(evolution),
+            _ => {}
+        }
+    }
+}
+
+impl Display for Upgrade {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Upgrade::MaxHealth => write!(f, "+{} Max HP", UPGRADE_MAX_HEALTH_AMOUNT),
+            Upgrade::Regen => write!(f, "+{} HP/s", UPGRADE_REGEN_AMOUNT),
+            Upgrade::PickupRadius => write!(f, "+{} Pickup", UPGRADE_PICKUP_RADIUS_AMOUNT),
+            Upgrade::Armor => write!(f, "+{} Armor", UPGRADE_ARMOR_AMOUNT),
+            Upgrade::Weapon(weapon) => write!(f, "{} Up", weapon),
+            Upgrade::Passive(passive) => write!(f, "{} Up", passive),
+            Upgrade::Evolution(evolution) => write!(f, "{}!", evolution.evolves_into),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use bevy::prelude::*;
+
+use crate::game::{
+    inventory::components::Inventory,
+    player::stats::PlayerStats,
+};
+
+use super::events::ApplyUpgradeEvent;
+
+pub fn apply_upgrades(
+    mut apply_upgrade_event_reader: EventReader<ApplyUpgradeEvent>,
+    mut player_query: Query<(&mut PlayerStats, &mut Inventory)>,
+) {
+    if let Ok((mut stats, mut inventory)) = player_query.get_single_mut() {
+        for event in &mut apply_upgrade_event_reader {
+            event.0.apply(&mut stats, &mut inventory);
+        }
+    }
+}
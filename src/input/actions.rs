@@ -0,0 +1,55 @@
+use std::fmt::Display;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Everything the player can do with a key, independent of which key it's bound to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Dash,
+    Pause,
+    ToMenu,
+}
+
+impl Action {
+    pub const ALL: [Action; 7] = [
+        Action::MoveUp,
+        Action::MoveDown,
+        Action::MoveLeft,
+        Action::MoveRight,
+        Action::Dash,
+        Action::Pause,
+        Action::ToMenu,
+    ];
+
+    /// The gamepad button that also performs this action. Unlike keys, these aren't rebindable.
+    pub fn gamepad_button(self) -> GamepadButtonType {
+        match self {
+            Action::MoveUp => GamepadButtonType::DPadUp,
+            Action::MoveDown => GamepadButtonType::DPadDown,
+            Action::MoveLeft => GamepadButtonType::DPadLeft,
+            Action::MoveRight => GamepadButtonType::DPadRight,
+            Action::Dash => GamepadButtonType::South,
+            Action::Pause => GamepadButtonType::Start,
+            Action::ToMenu => GamepadButtonType::Select,
+        }
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::MoveUp => write!(f, "Move Up"),
+            Action::MoveDown => write!(f, "Move Down"),
+            Action::MoveLeft => write!(f, "Move Left"),
+            Action::MoveRight => write!(f, "Move Right"),
+            Action::Dash => write!(f, "Dash"),
+            Action::Pause => write!(f, "Pause"),
+            Action::ToMenu => write!(f, "To Menu"),
+        }
+    }
+}
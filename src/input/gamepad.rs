@@ -0,0 +1,124 @@
+use bevy::prelude::*;
+
+use super::GAMEPAD_STICK_DEADZONE;
+
+/// Whether `button_type` is held on any connected gamepad.
+pub fn any_pressed(gamepad_buttons: &Input<GamepadButton>, button_type: GamepadButtonType) -> bool {
+    gamepad_buttons
+        .get_pressed()
+        .any(|button| button.button_type == button_type)
+}
+
+/// Whether `button_type` was pressed this frame on any connected gamepad.
+pub fn any_just_pressed(gamepad_buttons: &Input<GamepadButton>, button_type: GamepadButtonType) -> bool {
+    gamepad_buttons
+        .get_just_pressed()
+        .any(|button| button.button_type == button_type)
+}
+
+/// The left stick of the first gamepad pushed past the deadzone, rescaled so its length runs
+/// from zero at the deadzone's edge to one at full tilt.
+pub fn left_stick(gamepads: &Gamepads, gamepad_axes: &Axis<GamepadAxis>) -> Vec2 {
+    gamepads
+        .iter()
+        .map(|gamepad| {
+            Vec2::new(
+                gamepad_axes
+                    .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX))
+                    .unwrap_or_default(),
+                gamepad_axes
+                    .get(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY))
+                    .unwrap_or_default(),
+            )
+        })
+        .find(|stick| stick.length() > GAMEPAD_STICK_DEADZONE)
+        .map(|stick| {
+            let length = stick.length().min(1.0);
+            stick.normalize() * (length - GAMEPAD_STICK_DEADZONE) / (1.0 - GAMEPAD_STICK_DEADZONE)
+        })
+        .unwrap_or(Vec2::ZERO)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use bevy::input::{
+        gamepad::{
+            GamepadAxisChangedEvent, GamepadButtonChangedEvent, GamepadConnection,
+            GamepadConnectionEvent, GamepadEvent, GamepadInfo,
+        },
+        InputPlugin,
+    };
+
+    use super::*;
+
+    /// An app with Bevy's input handling and one gamepad plugged in, for driving systems that
+    /// read `Input<GamepadButton>` and `Axis<GamepadAxis>`.
+    pub fn app_with_gamepad() -> (App, Gamepad) {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin);
+        let gamepad = Gamepad::new(0);
+        app.world.send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected(GamepadInfo {
+                name: "Test Pad".to_string(),
+            }),
+        )));
+        app.update();
+        (app, gamepad)
+    }
+
+    /// Presses or lets go of `button_type`, taking effect on the next update.
+    pub fn set_button(app: &mut App, gamepad: Gamepad, button_type: GamepadButtonType, pressed: bool) {
+        let value = if pressed { 1.0 } else { 0.0 };
+        app.world.send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+            gamepad,
+            button_type,
+            value,
+        )));
+    }
+
+    /// Tilts the left stick to `stick`, taking effect on the next update.
+    pub fn set_left_stick(app: &mut App, gamepad: Gamepad, stick: Vec2) {
+        for (axis_type, value) in [
+            (GamepadAxisType::LeftStickX, stick.x),
+            (GamepadAxisType::LeftStickY, stick.y),
+        ] {
+            app.world.send_event(GamepadEvent::Axis(GamepadAxisChangedEvent::new(
+                gamepad, axis_type, value,
+            )));
+        }
+    }
+
+    fn read_left_stick(app: &mut App, gamepad: Gamepad, stick: Vec2) -> Vec2 {
+        set_left_stick(app, gamepad, stick);
+        app.update();
+        left_stick(
+            app.world.resource::<Gamepads>(),
+            app.world.resource::<Axis<GamepadAxis>>(),
+        )
+    }
+
+    #[test]
+    fn left_stick_ignores_the_deadzone() {
+        let (mut app, gamepad) = app_with_gamepad();
+        assert_eq!(read_left_stick(&mut app, gamepad, Vec2::new(0.1, 0.1)), Vec2::ZERO);
+    }
+
+    #[test]
+    fn left_stick_rescales_past_the_deadzone() {
+        let (mut app, gamepad) = app_with_gamepad();
+        let stick = read_left_stick(&mut app, gamepad, Vec2::new(0.6, 0.0));
+        assert!((stick - Vec2::new(0.5, 0.0)).length() < 1e-5, "{stick}");
+
+        let stick = read_left_stick(&mut app, gamepad, Vec2::new(0.0, -1.0));
+        assert!((stick - Vec2::new(0.0, -1.0)).length() < 1e-5, "{stick}");
+    }
+
+    #[test]
+    fn left_stick_caps_diagonals_at_full_tilt() {
+        let (mut app, gamepad) = app_with_gamepad();
+        let stick = read_left_stick(&mut app, gamepad, Vec2::new(1.0, 1.0));
+        assert!((stick.length() - 1.0).abs() < 1e-5, "{stick}");
+        assert!((stick.x - stick.y).abs() < 1e-5, "{stick}");
+    }
+}
@@ -0,0 +1,20 @@
+pub mod actions;
+pub mod gamepad;
+pub mod resources;
+
+use bevy::prelude::*;
+
+use self::resources::InputBindings;
+
+pub const INPUT_BINDINGS_FILE: &str = "input.ron";
+/// How far a stick has to be pushed before it counts, so worn sticks don't drift.
+pub const GAMEPAD_STICK_DEADZONE: f32 = 0.2;
+
+/// Loads the key bindings so they're ready before any system reads input.
+pub struct InputBindingsPlugin;
+
+impl Plugin for InputBindingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(InputBindings::load());
+    }
+}
@@ -0,0 +1,101 @@
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+
+use super::{actions::Action, gamepad, INPUT_BINDINGS_FILE};
+
+/// Which key each action is bound to.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputBindings {
+    keys: BTreeMap<Action, KeyCode>,
+}
+
+impl InputBindings {
+    /// The saved bindings, or the defaults if there are none. Actions missing from an older
+    /// file get their default key.
+    pub fn load() -> Self {
+        let mut bindings = InputBindings::default();
+        if let Some(saved) = config::load::<InputBindings>(INPUT_BINDINGS_FILE) {
+            bindings.keys.extend(saved.keys);
+        }
+        bindings
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = config::save(INPUT_BINDINGS_FILE, self) {
+            warn!("Couldn't save input bindings: {}", error);
+        }
+    }
+
+    pub fn key(&self, action: Action) -> Option<KeyCode> {
+        self.keys.get(&action).copied()
+    }
+
+    pub fn pressed(&self, action: Action, keyboard_input: &Input<KeyCode>) -> bool {
+        self.key(action).is_some_and(|key| keyboard_input.pressed(key))
+    }
+
+    pub fn just_pressed(&self, action: Action, keyboard_input: &Input<KeyCode>) -> bool {
+        self.key(action).is_some_and(|key| keyboard_input.just_pressed(key))
+    }
+
+    /// Like `pressed`, but also true while the action's gamepad button is held.
+    pub fn pressed_any(
+        &self,
+        action: Action,
+        keyboard_input: &Input<KeyCode>,
+        gamepad_buttons: &Input<GamepadButton>,
+    ) -> bool {
+        self.pressed(action, keyboard_input)
+            || gamepad::any_pressed(gamepad_buttons, action.gamepad_button())
+    }
+
+    /// Like `just_pressed`, but also true when the action's gamepad button was just pressed.
+    pub fn just_pressed_any(
+        &self,
+        action: Action,
+        keyboard_input: &Input<KeyCode>,
+        gamepad_buttons: &Input<GamepadButton>,
+    ) -> bool {
+        self.just_pressed(action, keyboard_input)
+            || gamepad::any_just_pressed(gamepad_buttons, action.gamepad_button())
+    }
+
+    /// Binds `key` to `action`. If another action already used that key, the two swap keys and
+    /// that action is returned so the conflict can be shown.
+    pub fn rebind(&mut self, action: Action, key: KeyCode) -> Option<Action> {
+        let conflict = self
+            .keys
+            .iter()
+            .find(|(other, other_key)| **other != action && **other_key == key)
+            .map(|(other, _)| *other);
+
+        let previous = self.keys.insert(action, key);
+        if let Some(conflict) = conflict {
+            match previous {
+                Some(previous) => self.keys.insert(conflict, previous),
+                None => self.keys.remove(&conflict),
+            };
+        }
+        conflict
+    }
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        InputBindings {
+            keys: BTreeMap::from([
+                (Action::MoveUp, KeyCode::W),
+                (Action::MoveDown, KeyCode::S),
+                (Action::MoveLeft, KeyCode::A),
+                (Action::MoveRight, KeyCode::D),
+                (Action::Dash, KeyCode::Space),
+                (Action::Pause, KeyCode::P),
+                (Action::ToMenu, KeyCode::M),
+            ]),
+        }
+    }
+}
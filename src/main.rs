@@ -1,12 +1,27 @@
+mod character_select;
+mod config;
+mod focus;
 mod game;
+mod input;
 mod main_menu;
+mod power_ups;
+mod records;
+mod save;
+mod settings;
 mod states;
 mod systems;
 
 use bevy::prelude::*;
+use character_select::CharacterSelectPlugin;
 // use bevy_inspector_egui::quick::WorldInspectorPlugin;
+use focus::FocusPlugin;
 use game::GamePlugin;
+use input::InputBindingsPlugin;
 use main_menu::MainMenuPlugin;
+use power_ups::PowerUpsPlugin;
+use records::RecordsPlugin;
+use save::SavePlugin;
+use settings::SettingsPlugin;
 use states::AppState;
 use systems::{spawn_camera, toggle_app_state};
 
@@ -16,7 +31,14 @@ fn main() {
     app.insert_resource(ClearColor(Color::BLACK))
         .add_plugins(DefaultPlugins)
         .add_state::<AppState>()
+        .add_plugins(SavePlugin)
+        .add_plugins(InputBindingsPlugin)
+        .add_plugins(FocusPlugin)
         .add_plugins(MainMenuPlugin)
+        .add_plugins(CharacterSelectPlugin)
+        .add_plugins(SettingsPlugin)
+        .add_plugins(PowerUpsPlugin)
+        .add_plugins(RecordsPlugin)
         .add_plugins(GamePlugin)
         .add_systems(Startup, spawn_camera)
         .add_systems(Update, toggle_app_state);
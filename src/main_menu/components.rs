@@ -5,6 +5,14 @@ pub struct MainMenu;
 
 #[derive(Component)]
 pub enum MainMenuButton {
+    Continue,
     Play,
+    WorldMode,
+    PowerUps,
+    Records,
+    Settings,
     Quit,
 }
+
+#[derive(Component)]
+pub struct WorldModeText;
@@ -1,16 +1,28 @@
 use bevy::{app::AppExit, prelude::*};
 
-use crate::states::AppState;
+use crate::{
+    focus::components::Focusable,
+    game::{
+        grid::resources::GridSettings,
+        player::resources::SelectedCharacter,
+        suspend::resources::{PendingResume, RunSnapshot},
+    },
+    states::AppState,
+};
 
 use super::{
-    components::{MainMenu, MainMenuButton},
+    components::{MainMenu, MainMenuButton, WorldModeText},
     styles::{
         get_button_text, HOVERED_BUTTON_COLOR, MAIN_MENU_STYLE, NORMAL_BUTTON_COLOR,
         NORMAL_BUTTON_STYLE, PRESSED_BUTTON_COLOR,
     },
 };
 
-pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+pub fn spawn_main_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    grid_settings: Res<GridSettings>,
+) {
     commands
         .spawn((
             NodeBundle {
@@ -21,6 +33,24 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
             Name::from("Main Menu"),
         ))
         .with_children(|parent| {
+            // ---- Continue Button ----
+            if RunSnapshot::exists() {
+                parent
+                    .spawn((
+                        ButtonBundle {
+                            style: NORMAL_BUTTON_STYLE,
+                            ..default()
+                        },
+                        MainMenuButton::Continue,
+                        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn(TextBundle {
+                            text: get_button_text(&asset_server, "Continue"),
+                            ..default()
+                        });
+                    });
+            }
             // ---- Play Button ----
             parent
                 .spawn((
@@ -29,6 +59,7 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                         ..default()
                     },
                     MainMenuButton::Play,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
                 ))
                 .with_children(|parent| {
                     parent.spawn(TextBundle {
@@ -36,6 +67,73 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                         ..default()
                     });
                 });
+            // ---- World Mode Button ----
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: NORMAL_BUTTON_STYLE,
+                        ..default()
+                    },
+                    MainMenuButton::WorldMode,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        TextBundle {
+                            text: get_button_text(&asset_server, &grid_settings.mode.to_string()),
+                            ..default()
+                        },
+                        WorldModeText {},
+                    ));
+                });
+            // ---- Power Ups Button ----
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: NORMAL_BUTTON_STYLE,
+                        ..default()
+                    },
+                    MainMenuButton::PowerUps,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_button_text(&asset_server, "Power Ups"),
+                        ..default()
+                    });
+                });
+            // ---- Records Button ----
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: NORMAL_BUTTON_STYLE,
+                        ..default()
+                    },
+                    MainMenuButton::Records,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_button_text(&asset_server, "Records"),
+                        ..default()
+                    });
+                });
+            // ---- Settings Button ----
+            parent
+                .spawn((
+                    ButtonBundle {
+                        style: NORMAL_BUTTON_STYLE,
+                        ..default()
+                    },
+                    MainMenuButton::Settings,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+                ))
+                .with_children(|parent| {
+                    parent.spawn(TextBundle {
+                        text: get_button_text(&asset_server, "Settings"),
+                        ..default()
+                    });
+                });
             // ---- Quit Button ----
             parent
                 .spawn((
@@ -44,6 +142,7 @@ pub fn spawn_main_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
                         ..default()
                     },
                     MainMenuButton::Quit,
+                    Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
                 ))
                 .with_children(|parent| {
                     parent.spawn(TextBundle {
@@ -61,18 +160,54 @@ pub fn despawn_main_menu(mut commands: Commands, main_menu_query: Query<Entity,
 }
 
 pub fn button_interaction(
+    mut commands: Commands,
     mut button_query: Query<
-        (&Interaction, &mut BackgroundColor, &MainMenuButton),
+        (Entity, &Interaction, &mut BackgroundColor, &MainMenuButton),
         Changed<Interaction>,
     >,
+    mut world_mode_text_query: Query<&mut Text, With<WorldModeText>>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut app_exit_event_writer: EventWriter<AppExit>,
+    mut grid_settings: ResMut<GridSettings>,
+    mut selected_character: ResMut<SelectedCharacter>,
 ) {
-    for (interaction, mut background_color, menu_button_option) in button_query.iter_mut() {
+    for (entity, interaction, mut background_color, menu_button_option) in button_query.iter_mut() {
         match (*interaction, menu_button_option) {
+            (Interaction::Pressed, MainMenuButton::Continue) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                match RunSnapshot::take() {
+                    Some(snapshot) => {
+                        grid_settings.mode = snapshot.world_mode;
+                        selected_character.0 = snapshot.character.clone();
+                        commands.insert_resource(PendingResume(snapshot));
+                        next_app_state.set(AppState::Game);
+                    }
+                    // The run couldn't be read, so there's nothing left to continue.
+                    None => commands.entity(entity).despawn_recursive(),
+                }
+            }
             (Interaction::Pressed, MainMenuButton::Play) => {
                 *background_color = PRESSED_BUTTON_COLOR.into();
-                next_app_state.set(AppState::Game);
+                next_app_state.set(AppState::CharacterSelect);
+            }
+            (Interaction::Pressed, MainMenuButton::WorldMode) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                grid_settings.mode = grid_settings.mode.next();
+                if let Ok(mut world_mode_text) = world_mode_text_query.get_single_mut() {
+                    world_mode_text.sections[0].value = grid_settings.mode.to_string();
+                }
+            }
+            (Interaction::Pressed, MainMenuButton::PowerUps) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::PowerUps);
+            }
+            (Interaction::Pressed, MainMenuButton::Records) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::Records);
+            }
+            (Interaction::Pressed, MainMenuButton::Settings) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::Settings);
             }
             (Interaction::Pressed, MainMenuButton::Quit) => {
                 *background_color = PRESSED_BUTTON_COLOR.into();
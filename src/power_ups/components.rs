@@ -0,0 +1,24 @@
+use bevy::prelude::Component;
+
+use super::items::PowerUp;
+
+#[derive(Component)]
+pub struct PowerUpsMenu;
+
+#[derive(Component)]
+pub enum PowerUpsMenuButton {
+    Buy(PowerUp),
+    Refund,
+    Back,
+}
+
+/// The label of a buy button, showing the power up's rank and next price.
+#[derive(Component)]
+pub struct PowerUpText(pub PowerUp);
+
+#[derive(Component)]
+pub struct CoinsText;
+
+/// Says why a purchase didn't go through, or what a refund gave back.
+#[derive(Component)]
+pub struct PowerUpsStatusText;
@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::game::player::stats::{Modifier, ModifierKind, ModifierSource, Stat};
+
+use super::{
+    POWER_UP_DAMAGE_PER_RANK, POWER_UP_MAX_HEALTH_PER_RANK, POWER_UP_MAX_RANK,
+    POWER_UP_MOVE_SPEED_PER_RANK, POWER_UP_PICKUP_RADIUS_PER_RANK,
+};
+
+/// Permanent starting bonuses bought with coins between runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PowerUp {
+    MaxHealth,
+    Damage,
+    PickupRadius,
+    MoveSpeed,
+}
+
+impl PowerUp {
+    pub const ALL: [PowerUp; 4] = [
+        PowerUp::MaxHealth,
+        PowerUp::Damage,
+        PowerUp::PickupRadius,
+        PowerUp::MoveSpeed,
+    ];
+
+    pub fn max_rank(&self) -> u32 {
+        POWER_UP_MAX_RANK
+    }
+
+    /// What buying the next rank costs, when `rank` ranks are already owned. Every rank costs
+    /// the first one's price more than the last.
+    pub fn cost(&self, rank: u32) -> u32 {
+        let base = match self {
+            PowerUp::MaxHealth => 5,
+            PowerUp::Damage => 8,
+            PowerUp::PickupRadius => 4,
+            PowerUp::MoveSpeed => 6,
+        };
+        base * (rank + 1)
+    }
+
+    /// Everything spent on the first `rank` ranks, given back by a refund.
+    pub fn total_cost(&self, rank: u32) -> u32 {
+        (0..rank).map(|rank| self.cost(rank)).sum()
+    }
+
+    /// The stat modifier this power up gives at the given rank.
+    pub fn modifier(&self, rank: u32) -> Modifier {
+        let rank = rank as f32;
+        let (stat, kind) = match self {
+            PowerUp::MaxHealth => (
+                Stat::MaxHealth,
+                ModifierKind::Additive(POWER_UP_MAX_HEALTH_PER_RANK * rank),
+            ),
+            PowerUp::Damage => (
+                Stat::Damage,
+                ModifierKind::Additive(POWER_UP_DAMAGE_PER_RANK * rank),
+            ),
+            PowerUp::PickupRadius => (
+                Stat::PickupRadius,
+                ModifierKind::Multiplicative(1.0 + POWER_UP_PICKUP_RADIUS_PER_RANK * rank),
+            ),
+            PowerUp::MoveSpeed => (
+                Stat::MoveSpeed,
+                ModifierKind::Multiplicative(1.0 + POWER_UP_MOVE_SPEED_PER_RANK * rank),
+            ),
+        };
+        Modifier {
+            stat,
+            source: ModifierSource::PowerUp(*self),
+            kind,
+        }
+    }
+}
+
+impl Display for PowerUp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PowerUp::MaxHealth => write!(f, "Max Health"),
+            PowerUp::Damage => write!(f, "Damage"),
+            PowerUp::PickupRadius => write!(f, "Pickup Radius"),
+            PowerUp::MoveSpeed => write!(f, "Move Speed"),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+mod components;
+pub mod items;
+mod styles;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::{save::resources::SaveData, states::AppState};
+
+use self::systems::{button_interaction, despawn_power_ups_menu, spawn_power_ups_menu, update_power_up_texts};
+
+pub const POWER_UP_MAX_RANK: u32 = 5;
+pub const POWER_UP_MAX_HEALTH_PER_RANK: f32 = 2.0;
+pub const POWER_UP_DAMAGE_PER_RANK: f32 = 0.05;
+pub const POWER_UP_PICKUP_RADIUS_PER_RANK: f32 = 0.1;
+pub const POWER_UP_MOVE_SPEED_PER_RANK: f32 = 0.05;
+
+/// Runs the power ups shop, opened from the main menu as `AppState::PowerUps`. Bought ranks
+/// are kept in the save file and given to the player at the start of every run.
+pub struct PowerUpsPlugin;
+
+impl Plugin for PowerUpsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::PowerUps), spawn_power_ups_menu)
+            .add_systems(
+                Update,
+                (
+                    button_interaction,
+                    update_power_up_texts.run_if(resource_changed::<SaveData>()),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::PowerUps)),
+            )
+            .add_systems(OnExit(AppState::PowerUps), despawn_power_ups_menu);
+    }
+}
@@ -0,0 +1,229 @@
+use bevy::prelude::*;
+
+use crate::{
+    focus::components::{FocusBack, Focusable},
+    game::achievements::{
+        definitions::{Achievements, Unlock},
+        resources::AchievementsHandle,
+    },
+    save::resources::SaveData,
+    states::AppState,
+};
+
+use super::{
+    components::{CoinsText, PowerUpText, PowerUpsMenu, PowerUpsMenuButton, PowerUpsStatusText},
+    items::PowerUp,
+    styles::{
+        get_button_text, get_status_text, get_title_text, HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOR,
+        POWER_UPS_MENU_STYLE, POWER_UPS_ROW_STYLE, POWER_UPS_SMALL_BUTTON_STYLE,
+        POWER_UP_BUTTON_STYLE, PRESSED_BUTTON_COLOR,
+    },
+};
+
+pub fn spawn_power_ups_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    save_data: Res<SaveData>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: POWER_UPS_MENU_STYLE,
+                ..default()
+            },
+            PowerUpsMenu {},
+            Name::from("Power Ups Menu"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: get_title_text(&asset_server, "Power Ups"),
+                ..default()
+            });
+            parent.spawn((
+                TextBundle {
+                    text: get_button_text(&asset_server, &coins_label(&save_data)),
+                    ..default()
+                },
+                CoinsText {},
+            ));
+
+            for power_up in PowerUp::ALL {
+                spawn_power_ups_button(
+                    parent,
+                    POWER_UP_BUTTON_STYLE,
+                    PowerUpsMenuButton::Buy(power_up),
+                    (
+                        TextBundle {
+                            text: get_button_text(&asset_server, &power_up_label(power_up, &save_data)),
+                            ..default()
+                        },
+                        PowerUpText(power_up),
+                    ),
+                );
+            }
+
+            parent.spawn((
+                TextBundle {
+                    text: get_status_text(&asset_server, ""),
+                    ..default()
+                },
+                PowerUpsStatusText {},
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: POWER_UPS_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (text, button_type) in [
+                        ("Refund", PowerUpsMenuButton::Refund),
+                        ("Back", PowerUpsMenuButton::Back),
+                    ] {
+                        spawn_power_ups_button(
+                            parent,
+                            POWER_UPS_SMALL_BUTTON_STYLE,
+                            button_type,
+                            TextBundle {
+                                text: get_button_text(&asset_server, text),
+                                ..default()
+                            },
+                        );
+                    }
+                });
+        });
+}
+
+fn spawn_power_ups_button(
+    parent: &mut ChildBuilder,
+    style: Style,
+    button_type: PowerUpsMenuButton,
+    label: impl Bundle,
+) {
+    let is_back = matches!(button_type, PowerUpsMenuButton::Back);
+    let mut button = parent.spawn((
+        ButtonBundle { style, ..default() },
+        button_type,
+        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+    ));
+    if is_back {
+        button.insert(FocusBack);
+    }
+    button.with_children(|parent| {
+        parent.spawn(label);
+    });
+}
+
+fn coins_label(save_data: &SaveData) -> String {
+    format!("Coins: {}", save_data.coins)
+}
+
+fn power_up_label(power_up: PowerUp, save_data: &SaveData) -> String {
+    let rank = save_data.power_up_rank(power_up);
+    if !save_data.is_unlocked(&Unlock::PowerUp(power_up)) {
+        format!("{}: Locked", power_up)
+    } else if rank >= power_up.max_rank() {
+        format!("{} {}/{}: Maxed", power_up, rank, power_up.max_rank())
+    } else {
+        format!(
+            "{} {}/{}: {} coins",
+            power_up,
+            rank,
+            power_up.max_rank(),
+            power_up.cost(rank)
+        )
+    }
+}
+
+pub fn despawn_power_ups_menu(
+    mut commands: Commands,
+    power_ups_menu_query: Query<Entity, With<PowerUpsMenu>>,
+) {
+    if let Ok(power_ups_menu_entity) = power_ups_menu_query.get_single() {
+        commands.entity(power_ups_menu_entity).despawn_recursive();
+    }
+}
+
+/// Buys the next rank of a power up if it's unlocked and there are coins for it, or refunds
+/// every rank bought. The save is written straight away, so purchases can't be lost.
+pub fn button_interaction(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &PowerUpsMenuButton),
+        Changed<Interaction>,
+    >,
+    mut status_text_query: Query<&mut Text, With<PowerUpsStatusText>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut save_data: ResMut<SaveData>,
+    achievements_handle: Res<AchievementsHandle>,
+    achievements: Res<Assets<Achievements>>,
+) {
+    for (interaction, mut background_color, power_ups_button) in button_query.iter_mut() {
+        match (*interaction, power_ups_button) {
+            (Interaction::Pressed, PowerUpsMenuButton::Buy(power_up)) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                let rank = save_data.power_up_rank(*power_up);
+                let cost = power_up.cost(rank);
+                let unlock = Unlock::PowerUp(*power_up);
+                let status = if !save_data.is_unlocked(&unlock) {
+                    match achievements
+                        .get(&achievements_handle.0)
+                        .and_then(|achievements| achievements.unlocking(&unlock))
+                    {
+                        Some(achievement) => format!(
+                            "{} is unlocked by {}: {}",
+                            power_up, achievement.name, achievement.description
+                        ),
+                        None => format!("{} is locked", power_up),
+                    }
+                } else if rank >= power_up.max_rank() {
+                    format!("{} is already maxed", power_up)
+                } else if save_data.coins < cost {
+                    format!("{} needs {} more coins", power_up, cost - save_data.coins)
+                } else {
+                    save_data.coins -= cost;
+                    save_data.power_ups.insert(*power_up, rank + 1);
+                    save_data.save();
+                    String::new()
+                };
+                set_status(&mut status_text_query, status);
+            }
+            (Interaction::Pressed, PowerUpsMenuButton::Refund) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                let refund: u32 = save_data
+                    .power_ups
+                    .iter()
+                    .map(|(power_up, rank)| power_up.total_cost(*rank))
+                    .sum();
+                save_data.coins += refund;
+                save_data.power_ups.clear();
+                save_data.save();
+                set_status(&mut status_text_query, format!("Refunded {} coins", refund));
+            }
+            (Interaction::Pressed, PowerUpsMenuButton::Back) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::MainMenu);
+            }
+            (Interaction::Hovered, _) => *background_color = HOVERED_BUTTON_COLOR.into(),
+            _ => *background_color = NORMAL_BUTTON_COLOR.into(),
+        }
+    }
+}
+
+fn set_status(status_text_query: &mut Query<&mut Text, With<PowerUpsStatusText>>, status: String) {
+    if let Ok(mut status_text) = status_text_query.get_single_mut() {
+        status_text.sections[0].value = status;
+    }
+}
+
+pub fn update_power_up_texts(
+    mut power_up_text_query: Query<(&mut Text, &PowerUpText), Without<CoinsText>>,
+    mut coins_text_query: Query<&mut Text, With<CoinsText>>,
+    save_data: Res<SaveData>,
+) {
+    for (mut text, power_up_text) in &mut power_up_text_query {
+        text.sections[0].value = power_up_label(power_up_text.0, &save_data);
+    }
+    if let Ok(mut coins_text) = coins_text_query.get_single_mut() {
+        coins_text.sections[0].value = coins_label(&save_data);
+    }
+}
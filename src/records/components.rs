@@ -0,0 +1,20 @@
+use bevy::prelude::Component;
+
+use super::resources::RunSort;
+
+#[derive(Component)]
+pub struct RecordsMenu;
+
+#[derive(Component)]
+pub enum RecordsMenuButton {
+    Sort(RunSort),
+    Back,
+}
+
+/// Says what the best runs are sorted by.
+#[derive(Component)]
+pub struct BestRunsTitle;
+
+/// Holds a line for each of the best runs, rebuilt whenever the sort changes.
+#[derive(Component)]
+pub struct BestRunsList;
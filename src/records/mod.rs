@@ -0,0 +1,39 @@
+mod components;
+mod resources;
+mod styles;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::{game::history::resources::RunHistory, states::AppState};
+
+use self::{
+    resources::RunSort,
+    systems::{button_interaction, despawn_records_menu, spawn_records_menu, update_best_runs},
+};
+
+/// How many of the best runs are listed.
+pub const RECORDS_SHOWN: usize = 10;
+
+/// Shows the run history as the records screen, opened from the main menu as
+/// `AppState::Records`: the best runs by the chosen `RunSort`, and each character's bests.
+pub struct RecordsPlugin;
+
+impl Plugin for RecordsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunSort>()
+            .add_systems(OnEnter(AppState::Records), spawn_records_menu)
+            .add_systems(
+                Update,
+                (
+                    button_interaction,
+                    update_best_runs.run_if(
+                        resource_changed::<RunSort>().or_else(resource_added::<RunHistory>()),
+                    ),
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Records)),
+            )
+            .add_systems(OnExit(AppState::Records), despawn_records_menu);
+    }
+}
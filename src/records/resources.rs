@@ -0,0 +1,41 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use bevy::prelude::Resource;
+
+use crate::game::history::resources::RunRecord;
+
+/// How the records screen orders the best runs. Kept between visits.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RunSort {
+    #[default]
+    Time,
+    Level,
+    Kills,
+    Recent,
+}
+
+impl RunSort {
+    pub const ALL: [RunSort; 4] = [RunSort::Time, RunSort::Level, RunSort::Kills, RunSort::Recent];
+
+    /// Orders runs best first. Ties go to the longer run.
+    pub fn compare(&self, a: &RunRecord, b: &RunRecord) -> Ordering {
+        let ordering = match self {
+            RunSort::Time => Ordering::Equal,
+            RunSort::Level => b.level.cmp(&a.level),
+            RunSort::Kills => b.kills.cmp(&a.kills),
+            RunSort::Recent => b.date.cmp(&a.date),
+        };
+        ordering.then(b.duration.total_cmp(&a.duration))
+    }
+}
+
+impl Display for RunSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RunSort::Time => write!(f, "Time"),
+            RunSort::Level => write!(f, "Level"),
+            RunSort::Kills => write!(f, "Kills"),
+            RunSort::Recent => write!(f, "Recent"),
+        }
+    }
+}
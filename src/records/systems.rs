@@ -0,0 +1,296 @@
+use bevy::prelude::*;
+
+use crate::{
+    focus::components::{FocusBack, Focusable},
+    game::{
+        history::resources::{RunHistory, RunRecord},
+        player::{
+            characters::{Character, Characters},
+            resources::CharactersHandle,
+        },
+    },
+    states::AppState,
+};
+
+use super::{
+    components::{BestRunsList, BestRunsTitle, RecordsMenu, RecordsMenuButton},
+    resources::RunSort,
+    styles::{
+        get_button_text, get_heading_text, get_record_text, get_title_text, BACK_BUTTON_STYLE,
+        HOVERED_BUTTON_COLOR, NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR, RECORDS_COLUMNS_STYLE,
+        RECORDS_COLUMN_STYLE, RECORDS_MENU_STYLE, RECORDS_ROW_STYLE, SORT_BUTTON_STYLE,
+    },
+    RECORDS_SHOWN,
+};
+
+/// Reads the run history and lays out the screen. The best runs are filled in by
+/// `update_best_runs`.
+pub fn spawn_records_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    characters_handle: Res<CharactersHandle>,
+    characters: Res<Assets<Characters>>,
+) {
+    let run_history = RunHistory::load();
+    let characters = match characters.get(&characters_handle.0) {
+        Some(characters) => characters.characters.clone(),
+        None => vec![Character::default()],
+    };
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: RECORDS_MENU_STYLE,
+                ..default()
+            },
+            RecordsMenu {},
+            Name::from("Records Menu"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: get_title_text(&asset_server, "Records"),
+                ..default()
+            });
+
+            parent
+                .spawn(NodeBundle {
+                    style: RECORDS_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for sort in RunSort::ALL {
+                        spawn_records_button(
+                            parent,
+                            &asset_server,
+                            SORT_BUTTON_STYLE,
+                            RecordsMenuButton::Sort(sort),
+                            &sort.to_string(),
+                        );
+                    }
+                });
+
+            parent
+                .spawn(NodeBundle {
+                    style: RECORDS_COLUMNS_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent
+                        .spawn(NodeBundle {
+                            style: RECORDS_COLUMN_STYLE,
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn((
+                                TextBundle {
+                                    text: get_heading_text(&asset_server, ""),
+                                    ..default()
+                                },
+                                BestRunsTitle {},
+                            ));
+                            parent.spawn((
+                                NodeBundle {
+                                    style: RECORDS_COLUMN_STYLE,
+                                    ..default()
+                                },
+                                BestRunsList {},
+                            ));
+                        });
+
+                    parent
+                        .spawn(NodeBundle {
+                            style: RECORDS_COLUMN_STYLE,
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: get_heading_text(&asset_server, "Character Bests"),
+                                ..default()
+                            });
+                            for character in &characters {
+                                parent.spawn(TextBundle {
+                                    text: get_record_text(
+                                        &asset_server,
+                                        &character_best_line(character, &run_history.0),
+                                    ),
+                                    ..default()
+                                });
+                            }
+                        });
+                });
+
+            spawn_records_button(
+                parent,
+                &asset_server,
+                BACK_BUTTON_STYLE,
+                RecordsMenuButton::Back,
+                "Back",
+            );
+        });
+
+    commands.insert_resource(run_history);
+}
+
+fn spawn_records_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    style: Style,
+    button_type: RecordsMenuButton,
+    text: &str,
+) {
+    let is_back = matches!(button_type, RecordsMenuButton::Back);
+    let mut button = parent.spawn((
+        ButtonBundle { style, ..default() },
+        button_type,
+        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+    ));
+    if is_back {
+        button.insert(FocusBack);
+    }
+    button.with_children(|parent| {
+        parent.spawn(TextBundle {
+            text: get_button_text(asset_server, text),
+            ..default()
+        });
+    });
+}
+
+/// The character's longest run, highest level and most kills, which may be from different runs.
+fn character_best_line(character: &Character, runs: &[RunRecord]) -> String {
+    let runs: Vec<_> = runs.iter().filter(|run| run.character == character.id).collect();
+    if runs.is_empty() {
+        return format!("{}: no runs yet", character.name);
+    }
+    let duration = runs.iter().map(|run| run.duration).fold(0.0, f32::max);
+    let level = runs.iter().map(|run| run.level).max().unwrap_or_default();
+    let kills = runs.iter().map(|run| run.kills).max().unwrap_or_default();
+    format!(
+        "{}: {}  Lv {}  {} kills",
+        character.name,
+        format_duration(duration),
+        level,
+        kills
+    )
+}
+
+pub fn despawn_records_menu(
+    mut commands: Commands,
+    records_menu_query: Query<Entity, With<RecordsMenu>>,
+) {
+    if let Ok(records_menu_entity) = records_menu_query.get_single() {
+        commands.entity(records_menu_entity).despawn_recursive();
+    }
+    commands.remove_resource::<RunHistory>();
+}
+
+pub fn button_interaction(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &RecordsMenuButton),
+        Changed<Interaction>,
+    >,
+    mut run_sort: ResMut<RunSort>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, mut background_color, records_button) in button_query.iter_mut() {
+        match (*interaction, records_button) {
+            (Interaction::Pressed, RecordsMenuButton::Sort(sort)) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                *run_sort = *sort;
+            }
+            (Interaction::Pressed, RecordsMenuButton::Back) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                next_app_state.set(AppState::MainMenu);
+            }
+            (Interaction::Hovered, _) => *background_color = HOVERED_BUTTON_COLOR.into(),
+            _ => *background_color = NORMAL_BUTTON_COLOR.into(),
+        }
+    }
+}
+
+/// Lists the best runs in the chosen order.
+#[allow(clippy::too_many_arguments)]
+pub fn update_best_runs(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    run_history: Res<RunHistory>,
+    run_sort: Res<RunSort>,
+    characters_handle: Res<CharactersHandle>,
+    characters: Res<Assets<Characters>>,
+    best_runs_list_query: Query<Entity, With<BestRunsList>>,
+    mut best_runs_title_query: Query<&mut Text, With<BestRunsTitle>>,
+) {
+    let Ok(best_runs_list_entity) = best_runs_list_query.get_single() else {
+        return;
+    };
+    if let Ok(mut best_runs_title) = best_runs_title_query.get_single_mut() {
+        best_runs_title.sections[0].value = format!("Best Runs by {}", *run_sort);
+    }
+
+    let mut runs: Vec<_> = run_history.0.iter().collect();
+    runs.sort_by(|a, b| run_sort.compare(a, b));
+    let characters = characters.get(&characters_handle.0);
+
+    let mut best_runs_list = commands.entity(best_runs_list_entity);
+    best_runs_list.despawn_descendants();
+    best_runs_list.with_children(|parent| {
+        if runs.is_empty() {
+            parent.spawn(TextBundle {
+                text: get_record_text(&asset_server, "No runs yet"),
+                ..default()
+            });
+        }
+        for (rank, run) in runs.into_iter().take(RECORDS_SHOWN).enumerate() {
+            let character_name = characters
+                .and_then(|characters| {
+                    characters
+                        .characters
+                        .iter()
+                        .find(|character| character.id == run.character)
+                })
+                .map_or(run.character.as_str(), |character| character.name.as_str());
+            let ending = match run.cause_of_death {
+                Some(kind) => format!("killed by {}", kind),
+                None => "abandoned".to_string(),
+            };
+            parent.spawn(TextBundle {
+                text: get_record_text(
+                    &asset_server,
+                    &format!(
+                        "{}. {}  {}  {}  Lv {}  {} kills  {}",
+                        rank + 1,
+                        format_date(run.date),
+                        character_name,
+                        format_duration(run.duration),
+                        run.level,
+                        run.kills,
+                        ending
+                    ),
+                ),
+                ..default()
+            });
+        }
+    });
+}
+
+/// Minutes and seconds, like 12:05.
+fn format_duration(seconds: f32) -> String {
+    let seconds = seconds as u64;
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// The UTC date of a Unix time, like 2024-03-09.
+fn format_date(unix_seconds: u64) -> String {
+    // Converts days since the epoch to a civil date, with years starting in March so leap
+    // days fall at the end.
+    let days = (unix_seconds / 86_400) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    format!("{}-{:02}-{:02}", year, month, day)
+}
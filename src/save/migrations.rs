@@ -0,0 +1,80 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde_json::{Map, Value};
+
+use crate::power_ups::items::PowerUp;
+
+use super::{resources::SaveData, SAVE_VERSION};
+
+/// Upgrades one save version to the next, working on the raw JSON so fields can be renamed or
+/// restructured before the save is read into `SaveData`.
+type Migration = fn(&mut Map<String, Value>);
+
+/// `MIGRATIONS[n]` upgrades a version `n` save to version `n + 1`.
+const MIGRATIONS: [Migration; SAVE_VERSION as usize] = [unversioned_to_v1, v1_to_v2];
+
+/// Brings a save of `version` up to `SAVE_VERSION`, one version at a time.
+pub fn migrate(save: &mut Map<String, Value>, version: u32) {
+    for migration in &MIGRATIONS[version as usize..] {
+        migration(save);
+    }
+    save.insert("version".to_string(), Value::from(SAVE_VERSION));
+}
+
+/// Saves from before the version field are otherwise laid out like version 1.
+fn unversioned_to_v1(_save: &mut Map<String, Value>) {}
+
+/// Version 2 locks power ups behind achievements. Any that were already bought stay unlocked,
+/// along with those every save starts with.
+fn v1_to_v2(save: &mut Map<String, Value>) {
+    let mut unlocked: BTreeSet<PowerUp> = save
+        .get("unlocked_power_ups")
+        .and_then(|unlocked| serde_json::from_value(unlocked.clone()).ok())
+        .unwrap_or_else(|| SaveData::default().unlocked_power_ups);
+    let ranks: BTreeMap<PowerUp, u32> = save
+        .get("power_ups")
+        .and_then(|ranks| serde_json::from_value(ranks.clone()).ok())
+        .unwrap_or_default();
+    unlocked.extend(
+        ranks
+            .into_iter()
+            .filter(|(_, rank)| *rank > 0)
+            .map(|(power_up, _)| power_up),
+    );
+
+    if let Ok(unlocked) = serde_json::to_value(unlocked) {
+        save.insert("unlocked_power_ups".to_string(), unlocked);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_stamps_the_current_version() {
+        let mut save = Map::new();
+
+        migrate(&mut save, 0);
+
+        assert_eq!(save.get("version"), Some(&Value::from(SAVE_VERSION)));
+    }
+
+    #[test]
+    fn migrate_unlocks_bought_power_ups() {
+        let mut save = Map::new();
+        save.insert(
+            "power_ups".to_string(),
+            serde_json::json!({ "MoveSpeed": 2, "PickupRadius": 0 }),
+        );
+
+        migrate(&mut save, 1);
+
+        let unlocked: BTreeSet<PowerUp> =
+            serde_json::from_value(save["unlocked_power_ups"].clone()).unwrap();
+        let mut expected = SaveData::default().unlocked_power_ups;
+        expected.insert(PowerUp::MoveSpeed);
+        assert_eq!(unlocked, expected);
+    }
+
+}
@@ -0,0 +1,25 @@
+mod migrations;
+pub mod resources;
+mod systems;
+
+use bevy::prelude::*;
+
+use crate::states::AppState;
+
+use self::{resources::SaveData, systems::save_progress};
+
+pub const SAVE_FILE: &str = "save.json";
+/// The version new saves are written as. Bump it alongside a new migration whenever the save
+/// changes in a way `#[serde(default)]` can't absorb.
+pub const SAVE_VERSION: u32 = 2;
+
+/// Loads the save file before anything reads it. Progress made during a run is written when the
+/// game is left.
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(SaveData::load())
+            .add_systems(OnExit(AppState::Game), save_progress);
+    }
+}
@@ -0,0 +1,213 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    config,
+    game::{
+        achievements::{definitions::Unlock, conditions::AchievementProgress},
+        inventory::items::Weapon,
+    },
+    power_ups::items::PowerUp,
+};
+
+use super::{migrations, SAVE_FILE, SAVE_VERSION};
+
+/// Everything kept between runs and launches.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaveData {
+    pub version: u32,
+    pub unlocked_characters: BTreeSet<String>,
+    pub unlocked_weapons: BTreeSet<Weapon>,
+    pub achievements: BTreeSet<String>,
+    /// Progress towards achievements that add up over every run.
+    pub achievement_progress: AchievementProgress,
+    pub coins: u32,
+    /// The ranks bought of each power up. Power ups never bought are left out.
+    pub power_ups: BTreeMap<PowerUp, u32>,
+    /// Power ups that can be bought in the shop.
+    pub unlocked_power_ups: BTreeSet<PowerUp>,
+    /// The longest each character has survived, in seconds.
+    pub best_times: BTreeMap<String, f32>,
+}
+
+impl SaveData {
+    /// The save file, migrated to the current version. A missing file gives a fresh save; an
+    /// unreadable one is moved aside so it isn't overwritten, and a fresh save is used instead.
+    pub fn load() -> Self {
+        let Some(path) = config::data_path(SAVE_FILE) else {
+            return SaveData::default();
+        };
+        let contents = match fs::read(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return SaveData::default(),
+            Err(error) => {
+                warn!("Couldn't read save file {}: {}", path.display(), error);
+                return SaveData::default();
+            }
+        };
+
+        match SaveData::parse(&contents) {
+            Ok(save_data) => save_data,
+            Err(error) => {
+                warn!("Save file {} is unreadable: {}", path.display(), error);
+                back_up(&path);
+                SaveData::default()
+            }
+        }
+    }
+
+    fn parse(contents: &[u8]) -> Result<Self, String> {
+        let mut value: Value = serde_json::from_slice(contents).map_err(|error| error.to_string())?;
+        let save = value.as_object_mut().ok_or("the save isn't a JSON object")?;
+
+        let version = match save.get("version") {
+            Some(version) => version.as_u64().ok_or("the version isn't a number")? as u32,
+            None => 0,
+        };
+        if version > SAVE_VERSION {
+            return Err(format!(
+                "it's version {}, but this game only knows up to version {}",
+                version, SAVE_VERSION
+            ));
+        }
+        migrations::migrate(save, version);
+
+        serde_json::from_value(value).map_err(|error| error.to_string())
+    }
+
+    pub fn power_up_rank(&self, power_up: PowerUp) -> u32 {
+        self.power_ups.get(&power_up).copied().unwrap_or_default()
+    }
+
+    pub fn is_unlocked(&self, unlock: &Unlock) -> bool {
+        match unlock {
+            Unlock::Character(id) => self.unlocked_characters.contains(id),
+            Unlock::Weapon(weapon) => self.unlocked_weapons.contains(weapon),
+            Unlock::PowerUp(power_up) => self.unlocked_power_ups.contains(power_up),
+        }
+    }
+
+    pub fn unlock(&mut self, unlock: &Unlock) {
+        match unlock {
+            Unlock::Character(id) => {
+                self.unlocked_characters.insert(id.clone());
+            }
+            Unlock::Weapon(weapon) => {
+                self.unlocked_weapons.insert(*weapon);
+            }
+            Unlock::PowerUp(power_up) => {
+                self.unlocked_power_ups.insert(*power_up);
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = config::data_path(SAVE_FILE) else {
+            warn!("Couldn't save: no data directory");
+            return;
+        };
+        let result = serde_json::to_string_pretty(self)
+            .map_err(io::Error::from)
+            .and_then(|contents| config::write_atomic(&path, &contents));
+        if let Err(error) = result {
+            warn!("Couldn't save to {}: {}", path.display(), error);
+        }
+    }
+}
+
+impl Default for SaveData {
+    fn default() -> Self {
+        SaveData {
+            version: SAVE_VERSION,
+            unlocked_characters: BTreeSet::new(),
+            unlocked_weapons: BTreeSet::from([Weapon::Dot]),
+            achievements: BTreeSet::new(),
+            achievement_progress: AchievementProgress::default(),
+            coins: 0,
+            power_ups: BTreeMap::new(),
+            unlocked_power_ups: BTreeSet::from([PowerUp::MaxHealth, PowerUp::Damage]),
+            best_times: BTreeMap::new(),
+        }
+    }
+}
+
+/// Moves an unreadable save aside, stamped with the time so earlier backups are kept.
+fn back_up(path: &Path) {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+    let mut backup_path = path.as_os_str().to_owned();
+    backup_path.push(format!(".corrupt-{}", timestamp));
+
+    match fs::rename(path, &backup_path) {
+        Ok(()) => warn!("Moved it to {}", Path::new(&backup_path).display()),
+        Err(error) => warn!("Couldn't back it up: {}", error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use super::*;
+
+    #[test]
+    fn parse_migrates_unversioned_saves() {
+        let save_data = SaveData::parse(br#"{"coins": 12}"#).unwrap();
+
+        assert_eq!(save_data.version, SAVE_VERSION);
+        assert_eq!(save_data.coins, 12);
+    }
+
+    #[test]
+    fn parse_rejects_newer_versions() {
+        let contents = format!(r#"{{"version": {}}}"#, SAVE_VERSION + 1);
+
+        assert!(SaveData::parse(contents.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn parse_rejects_corrupt_saves() {
+        let corrupt: [&[u8]; 6] = [
+            b"",
+            br#"{"coins": 1"#,
+            b"[1, 2]",
+            br#"{"version": "one"}"#,
+            br#"{"coins": -1}"#,
+            b"{\"achievements\": [\"\xff\"]}",
+        ];
+        for contents in corrupt {
+            assert!(SaveData::parse(contents).is_err(), "{contents:?} parsed");
+        }
+    }
+
+    #[test]
+    fn back_up_moves_the_save_aside() {
+        let dir = std::env::temp_dir().join(format!("geometry-survivor-back-up-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(SAVE_FILE);
+        fs::write(&path, "not a save").unwrap();
+
+        back_up(&path);
+
+        let backups: Vec<_> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(!path.exists());
+        assert_eq!(backups.len(), 1);
+        let backup_name = backups[0].file_name().unwrap().to_string_lossy().into_owned();
+        assert!(backup_name.starts_with("save.json.corrupt-"), "{backup_name}");
+    }
+}
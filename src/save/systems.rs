@@ -0,0 +1,7 @@
+use bevy::prelude::*;
+
+use super::resources::SaveData;
+
+pub fn save_progress(save_data: Res<SaveData>) {
+    save_data.save();
+}
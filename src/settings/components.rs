@@ -0,0 +1,28 @@
+use bevy::prelude::Component;
+
+use crate::input::actions::Action;
+
+use super::options::SettingOption;
+
+#[derive(Component)]
+pub struct SettingsMenu;
+
+#[derive(Component)]
+pub enum SettingsMenuButton {
+    Rebind(Action),
+    Option(SettingOption),
+    Defaults,
+    Back,
+}
+
+/// The label of a rebind button, showing the action and its key.
+#[derive(Component)]
+pub struct BindingText(pub Action);
+
+/// The label of an option button, showing the option and its value.
+#[derive(Component)]
+pub struct OptionText(pub SettingOption);
+
+/// Tells the player what to press, or which binding a new key was taken from.
+#[derive(Component)]
+pub struct SettingsStatusText;
@@ -0,0 +1,69 @@
+mod components;
+mod options;
+pub mod resources;
+mod styles;
+mod systems;
+
+use bevy::{input::InputSystem, prelude::*, ui::UiSystem};
+
+use crate::{game::states::GameState, input::resources::InputBindings, states::AppState};
+
+use self::{
+    resources::{GameSettings, Rebinding},
+    systems::{
+        apply_settings, button_interaction, capture_rebind, despawn_settings_menu, settings_open,
+        spawn_settings_menu, update_binding_texts, update_option_texts,
+    },
+};
+
+pub const SETTINGS_FILE: &str = "settings.ron";
+/// Cancels waiting for a new key, so it can't be bound itself.
+pub const REBIND_CANCEL_KEY: KeyCode = KeyCode::Escape;
+pub const VOLUME_STEP: f32 = 0.1;
+pub const RESOLUTIONS: [(f32, f32); 4] = [
+    (1280.0, 720.0),
+    (1600.0, 900.0),
+    (1920.0, 1080.0),
+    (2560.0, 1440.0),
+];
+pub const UI_SCALES: [f64; 5] = [0.75, 1.0, 1.25, 1.5, 2.0];
+
+/// Loads the saved settings and keeps the window, UI and audio in step with them, and runs
+/// the settings screen.
+///
+/// The screen opens from the main menu as `AppState::Settings`, or over a paused game as
+/// `GameState::Settings`.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(GameSettings::load())
+            .init_resource::<Rebinding>()
+            .add_systems(OnEnter(AppState::Settings), spawn_settings_menu)
+            .add_systems(
+                OnEnter(GameState::Settings),
+                spawn_settings_menu.run_if(in_state(AppState::Game)),
+            )
+            .add_systems(
+                PreUpdate,
+                capture_rebind
+                    .after(InputSystem)
+                    .before(UiSystem::Focus)
+                    .run_if(settings_open),
+            )
+            .add_systems(
+                Update,
+                (
+                    button_interaction,
+                    update_binding_texts
+                        .run_if(resource_changed::<InputBindings>().or_else(resource_changed::<Rebinding>())),
+                    update_option_texts.run_if(resource_changed::<GameSettings>()),
+                )
+                    .chain()
+                    .run_if(settings_open),
+            )
+            .add_systems(Update, apply_settings.run_if(resource_changed::<GameSettings>()))
+            .add_systems(OnExit(AppState::Settings), despawn_settings_menu)
+            .add_systems(OnExit(GameState::Settings), despawn_settings_menu);
+    }
+}
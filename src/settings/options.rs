@@ -0,0 +1,108 @@
+use std::fmt::Display;
+
+use super::{resources::GameSettings, RESOLUTIONS, UI_SCALES, VOLUME_STEP};
+
+/// Each option on the settings screen. Pressing one steps it to its next value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingOption {
+    MasterVolume,
+    MusicVolume,
+    SfxVolume,
+    Fullscreen,
+    Resolution,
+    VSync,
+    DamageNumbers,
+    ScreenShake,
+    UiScale,
+}
+
+impl SettingOption {
+    pub const ALL: [SettingOption; 9] = [
+        SettingOption::MasterVolume,
+        SettingOption::MusicVolume,
+        SettingOption::SfxVolume,
+        SettingOption::Fullscreen,
+        SettingOption::Resolution,
+        SettingOption::VSync,
+        SettingOption::DamageNumbers,
+        SettingOption::ScreenShake,
+        SettingOption::UiScale,
+    ];
+
+    /// The option's name and current value, for its button.
+    pub fn label(self, settings: &GameSettings) -> String {
+        let value = match self {
+            SettingOption::MasterVolume => volume_label(settings.master_volume),
+            SettingOption::MusicVolume => volume_label(settings.music_volume),
+            SettingOption::SfxVolume => volume_label(settings.sfx_volume),
+            SettingOption::Fullscreen => toggle_label(settings.fullscreen),
+            SettingOption::Resolution => {
+                format!("{}x{}", settings.resolution.0, settings.resolution.1)
+            }
+            SettingOption::VSync => toggle_label(settings.vsync),
+            SettingOption::DamageNumbers => toggle_label(settings.damage_numbers),
+            SettingOption::ScreenShake => toggle_label(settings.screen_shake),
+            SettingOption::UiScale => format!("{}%", (settings.ui_scale * 100.0).round()),
+        };
+        format!("{}: {}", self, value)
+    }
+
+    /// Steps the option to its next value, wrapping around after the last.
+    pub fn cycle(self, settings: &mut GameSettings) {
+        match self {
+            SettingOption::MasterVolume => settings.master_volume = next_volume(settings.master_volume),
+            SettingOption::MusicVolume => settings.music_volume = next_volume(settings.music_volume),
+            SettingOption::SfxVolume => settings.sfx_volume = next_volume(settings.sfx_volume),
+            SettingOption::Fullscreen => settings.fullscreen = !settings.fullscreen,
+            SettingOption::Resolution => settings.resolution = next_of(&RESOLUTIONS, settings.resolution),
+            SettingOption::VSync => settings.vsync = !settings.vsync,
+            SettingOption::DamageNumbers => settings.damage_numbers = !settings.damage_numbers,
+            SettingOption::ScreenShake => settings.screen_shake = !settings.screen_shake,
+            SettingOption::UiScale => settings.ui_scale = next_of(&UI_SCALES, settings.ui_scale),
+        }
+    }
+}
+
+impl Display for SettingOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingOption::MasterVolume => write!(f, "Master Volume"),
+            SettingOption::MusicVolume => write!(f, "Music Volume"),
+            SettingOption::SfxVolume => write!(f, "SFX Volume"),
+            SettingOption::Fullscreen => write!(f, "Fullscreen"),
+            SettingOption::Resolution => write!(f, "Resolution"),
+            SettingOption::VSync => write!(f, "VSync"),
+            SettingOption::DamageNumbers => write!(f, "Damage Numbers"),
+            SettingOption::ScreenShake => write!(f, "Screen Shake"),
+            SettingOption::UiScale => write!(f, "UI Scale"),
+        }
+    }
+}
+
+fn volume_label(volume: f32) -> String {
+    format!("{}%", (volume * 100.0).round())
+}
+
+fn toggle_label(on: bool) -> String {
+    if on { "On" } else { "Off" }.to_string()
+}
+
+/// Steps up by `VOLUME_STEP`, back to silent after full volume.
+fn next_volume(volume: f32) -> f32 {
+    let steps = (1.0 / VOLUME_STEP).round();
+    let step = (volume / VOLUME_STEP).round() + 1.0;
+    if step > steps {
+        0.0
+    } else {
+        step * VOLUME_STEP
+    }
+}
+
+/// The choice after `current`, or the first if `current` isn't one of them, as it may not be
+/// when the settings file was edited by hand.
+fn next_of<T: Copy + PartialEq>(choices: &[T], current: T) -> T {
+    match choices.iter().position(|choice| *choice == current) {
+        Some(index) => choices[(index + 1) % choices.len()],
+        None => choices[0],
+    }
+}
@@ -0,0 +1,56 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{config, input::actions::Action};
+
+use super::SETTINGS_FILE;
+
+/// The action waiting for a new key, if any.
+#[derive(Resource, Debug, Default)]
+pub struct Rebinding(pub Option<Action>);
+
+/// Audio, video and gameplay options, saved between launches.
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub master_volume: f32,
+    pub music_volume: f32,
+    pub sfx_volume: f32,
+    pub fullscreen: bool,
+    /// The windowed size, in logical pixels.
+    pub resolution: (f32, f32),
+    pub vsync: bool,
+    pub damage_numbers: bool,
+    pub screen_shake: bool,
+    pub ui_scale: f64,
+}
+
+impl GameSettings {
+    /// The saved settings, or the defaults if there are none. Options missing from an older file
+    /// get their default.
+    pub fn load() -> Self {
+        config::load(SETTINGS_FILE).unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Err(error) = config::save(SETTINGS_FILE, self) {
+            warn!("Couldn't save settings: {}", error);
+        }
+    }
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        GameSettings {
+            master_volume: 1.0,
+            music_volume: 0.8,
+            sfx_volume: 0.8,
+            fullscreen: false,
+            resolution: (1280.0, 720.0),
+            vsync: true,
+            damage_numbers: true,
+            screen_shake: true,
+            ui_scale: 1.0,
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+pub const NORMAL_BUTTON_COLOR: Color = Color::hsla(0.0, 0.0, 0.15, 1.0);
+pub const HOVERED_BUTTON_COLOR: Color = Color::hsla(0.0, 0.0, 0.25, 1.0);
+pub const PRESSED_BUTTON_COLOR: Color = Color::hsla(125.0, 0.75, 0.5, 1.0);
+
+/// Dims the game behind the screen when it's opened from the pause menu.
+pub const SETTINGS_MENU_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.9);
+
+pub const SETTINGS_MENU_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Percent(100.0);
+    style.height = Val::Percent(100.0);
+    style.flex_direction = FlexDirection::Column;
+    style.justify_content = JustifyContent::Center;
+    style.align_items = AlignItems::Center;
+    style.row_gap = Val::Px(8.0);
+    style.column_gap = Val::Px(8.0);
+    style
+};
+
+/// Lays the controls and options out side by side.
+pub const SETTINGS_COLUMNS_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Row;
+    style.align_items = AlignItems::FlexStart;
+    style.column_gap = Val::Px(32.0);
+    style
+};
+
+pub const SETTINGS_COLUMN_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Column;
+    style.align_items = AlignItems::Center;
+    style.row_gap = Val::Px(8.0);
+    style
+};
+
+pub const SETTINGS_ROW_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.flex_direction = FlexDirection::Row;
+    style.column_gap = Val::Px(8.0);
+    style
+};
+
+pub const SETTINGS_BUTTON_STYLE: Style = {
+    let mut style = Style::DEFAULT;
+    style.width = Val::Px(400.0);
+    style.height = Val::Px(40.0);
+    style.align_items = AlignItems::Center;
+    style.justify_content = JustifyContent::Center;
+    style
+};
+
+pub fn get_title_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 48.0)
+}
+
+pub fn get_heading_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 36.0)
+}
+
+pub fn get_button_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 28.0)
+}
+
+pub fn get_status_text(asset_server: &Res<AssetServer>, text: &str) -> Text {
+    get_text(asset_server, text, 24.0)
+}
+
+fn get_text(asset_server: &Res<AssetServer>, text: &str, font_size: f32) -> Text {
+    Text {
+        sections: vec![TextSection::new(
+            text,
+            TextStyle {
+                font: asset_server.load("fonts/Davidfont.otf"),
+                font_size,
+                color: Color::Hsla {
+                    hue: 0.0,
+                    saturation: 0.0,
+                    lightness: 0.9,
+                    alpha: 1.0,
+                },
+            },
+        )],
+        alignment: TextAlignment::Center,
+        ..default()
+    }
+}
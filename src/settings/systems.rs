@@ -0,0 +1,331 @@
+use bevy::{
+    prelude::*,
+    window::{PresentMode, PrimaryWindow, WindowMode},
+};
+
+use crate::{
+    focus::components::{FocusBack, Focusable},
+    game::states::GameState,
+    input::{actions::Action, resources::InputBindings},
+    states::AppState,
+};
+
+use super::{
+    components::{BindingText, OptionText, SettingsMenu, SettingsMenuButton, SettingsStatusText},
+    options::SettingOption,
+    resources::{GameSettings, Rebinding},
+    styles::{
+        get_button_text, get_heading_text, get_status_text, get_title_text, HOVERED_BUTTON_COLOR,
+        NORMAL_BUTTON_COLOR, PRESSED_BUTTON_COLOR, SETTINGS_BUTTON_STYLE, SETTINGS_COLUMNS_STYLE,
+        SETTINGS_COLUMN_STYLE, SETTINGS_MENU_BACKGROUND_COLOR, SETTINGS_MENU_STYLE,
+        SETTINGS_ROW_STYLE,
+    },
+    REBIND_CANCEL_KEY,
+};
+
+pub fn spawn_settings_menu(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    input_bindings: Res<InputBindings>,
+    game_settings: Res<GameSettings>,
+) {
+    commands
+        .spawn((
+            NodeBundle {
+                style: SETTINGS_MENU_STYLE,
+                background_color: SETTINGS_MENU_BACKGROUND_COLOR.into(),
+                ..default()
+            },
+            SettingsMenu {},
+            Name::from("Settings Menu"),
+        ))
+        .with_children(|parent| {
+            parent.spawn(TextBundle {
+                text: get_title_text(&asset_server, "Settings"),
+                ..default()
+            });
+
+            parent
+                .spawn(NodeBundle {
+                    style: SETTINGS_COLUMNS_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // ---- Controls ----
+                    parent
+                        .spawn(NodeBundle {
+                            style: SETTINGS_COLUMN_STYLE,
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: get_heading_text(&asset_server, "Controls"),
+                                ..default()
+                            });
+
+                            for action in Action::ALL {
+                                spawn_labelled_button(
+                                    parent,
+                                    SettingsMenuButton::Rebind(action),
+                                    (
+                                        TextBundle {
+                                            text: get_button_text(
+                                                &asset_server,
+                                                &binding_label(action, &input_bindings, None),
+                                            ),
+                                            ..default()
+                                        },
+                                        BindingText(action),
+                                    ),
+                                );
+                            }
+                        });
+                    // ---- Options ----
+                    parent
+                        .spawn(NodeBundle {
+                            style: SETTINGS_COLUMN_STYLE,
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            parent.spawn(TextBundle {
+                                text: get_heading_text(&asset_server, "Options"),
+                                ..default()
+                            });
+
+                            for option in SettingOption::ALL {
+                                spawn_labelled_button(
+                                    parent,
+                                    SettingsMenuButton::Option(option),
+                                    (
+                                        TextBundle {
+                                            text: get_button_text(
+                                                &asset_server,
+                                                &option.label(&game_settings),
+                                            ),
+                                            ..default()
+                                        },
+                                        OptionText(option),
+                                    ),
+                                );
+                            }
+                        });
+                });
+
+            parent.spawn((
+                TextBundle {
+                    text: get_status_text(&asset_server, ""),
+                    ..default()
+                },
+                SettingsStatusText {},
+            ));
+
+            parent
+                .spawn(NodeBundle {
+                    style: SETTINGS_ROW_STYLE,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    spawn_settings_button(parent, &asset_server, "Defaults", SettingsMenuButton::Defaults);
+                    spawn_settings_button(parent, &asset_server, "Back", SettingsMenuButton::Back);
+                });
+        });
+}
+
+fn spawn_settings_button(
+    parent: &mut ChildBuilder,
+    asset_server: &Res<AssetServer>,
+    text: &str,
+    button_type: SettingsMenuButton,
+) {
+    spawn_labelled_button(
+        parent,
+        button_type,
+        TextBundle {
+            text: get_button_text(asset_server, text),
+            ..default()
+        },
+    );
+}
+
+/// Spawns a settings button with `label` as its text, for labels that get updated later.
+fn spawn_labelled_button(
+    parent: &mut ChildBuilder,
+    button_type: SettingsMenuButton,
+    label: impl Bundle,
+) {
+    let is_back = matches!(button_type, SettingsMenuButton::Back);
+    let mut button = parent.spawn((
+        ButtonBundle {
+            style: SETTINGS_BUTTON_STYLE,
+            ..default()
+        },
+        button_type,
+        Focusable::new(NORMAL_BUTTON_COLOR, HOVERED_BUTTON_COLOR),
+    ));
+    if is_back {
+        button.insert(FocusBack);
+    }
+    button.with_children(|parent| {
+        parent.spawn(label);
+    });
+}
+
+fn binding_label(action: Action, input_bindings: &InputBindings, rebinding: Option<Action>) -> String {
+    if rebinding == Some(action) {
+        return format!("{}: ...", action);
+    }
+    match input_bindings.key(action) {
+        Some(key) => format!("{}: {:?}", action, key),
+        None => format!("{}: -", action),
+    }
+}
+
+pub fn despawn_settings_menu(
+    mut commands: Commands,
+    settings_menu_query: Query<Entity, With<SettingsMenu>>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    if let Ok(settings_menu_entity) = settings_menu_query.get_single() {
+        commands.entity(settings_menu_entity).despawn_recursive();
+    }
+    rebinding.0 = None;
+}
+
+/// Whether the settings screen is open, from either the main menu or the pause menu.
+pub fn settings_open(
+    app_state: Res<State<AppState>>,
+    game_state: Res<State<GameState>>,
+) -> bool {
+    *app_state.get() == AppState::Settings || *game_state.get() == GameState::Settings
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn button_interaction(
+    mut button_query: Query<
+        (&Interaction, &mut BackgroundColor, &SettingsMenuButton),
+        Changed<Interaction>,
+    >,
+    mut status_text_query: Query<&mut Text, With<SettingsStatusText>>,
+    app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut input_bindings: ResMut<InputBindings>,
+    mut game_settings: ResMut<GameSettings>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    for (interaction, mut background_color, settings_button) in button_query.iter_mut() {
+        match (*interaction, settings_button) {
+            (Interaction::Pressed, SettingsMenuButton::Rebind(action)) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                rebinding.0 = Some(*action);
+                if let Ok(mut status_text) = status_text_query.get_single_mut() {
+                    status_text.sections[0].value = format!(
+                        "Press a key for {} ({:?} to cancel)",
+                        action, REBIND_CANCEL_KEY
+                    );
+                }
+            }
+            (Interaction::Pressed, SettingsMenuButton::Option(option)) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                option.cycle(&mut game_settings);
+                game_settings.save();
+            }
+            (Interaction::Pressed, SettingsMenuButton::Defaults) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                *input_bindings = InputBindings::default();
+                input_bindings.save();
+                *game_settings = GameSettings::default();
+                game_settings.save();
+                rebinding.0 = None;
+            }
+            (Interaction::Pressed, SettingsMenuButton::Back) => {
+                *background_color = PRESSED_BUTTON_COLOR.into();
+                match app_state.get() {
+                    AppState::Settings => next_app_state.set(AppState::MainMenu),
+                    _ => next_game_state.set(GameState::Paused),
+                }
+            }
+            (Interaction::Hovered, _) => *background_color = HOVERED_BUTTON_COLOR.into(),
+            _ => *background_color = NORMAL_BUTTON_COLOR.into(),
+        }
+    }
+}
+
+/// Binds the next key pressed to the action waiting for one, swapping keys with any action
+/// that already used it. The key is consumed, so menu focus doesn't also act on it.
+pub fn capture_rebind(
+    mut keyboard_input: ResMut<Input<KeyCode>>,
+    mut status_text_query: Query<&mut Text, With<SettingsStatusText>>,
+    mut input_bindings: ResMut<InputBindings>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    let Some(action) = rebinding.0 else {
+        return;
+    };
+    let Some(key) = keyboard_input.get_just_pressed().next().copied() else {
+        return;
+    };
+
+    rebinding.0 = None;
+    keyboard_input.clear_just_pressed(key);
+
+    let status = if key == REBIND_CANCEL_KEY {
+        String::new()
+    } else {
+        let status = match input_bindings.rebind(action, key) {
+            Some(conflict) => format!("{:?} was bound to {}, so they swapped", key, conflict),
+            None => String::new(),
+        };
+        input_bindings.save();
+        status
+    };
+
+    if let Ok(mut status_text) = status_text_query.get_single_mut() {
+        status_text.sections[0].value = status;
+    }
+}
+
+pub fn update_binding_texts(
+    mut binding_text_query: Query<(&mut Text, &BindingText)>,
+    input_bindings: Res<InputBindings>,
+    rebinding: Res<Rebinding>,
+) {
+    for (mut text, binding_text) in &mut binding_text_query {
+        text.sections[0].value = binding_label(binding_text.0, &input_bindings, rebinding.0);
+    }
+}
+
+pub fn update_option_texts(
+    mut option_text_query: Query<(&mut Text, &OptionText)>,
+    game_settings: Res<GameSettings>,
+) {
+    for (mut text, option_text) in &mut option_text_query {
+        text.sections[0].value = option_text.0.label(&game_settings);
+    }
+}
+
+/// Pushes the settings out to the window, UI and audio. Runs on startup too, as inserting the
+/// settings counts as a change.
+pub fn apply_settings(
+    game_settings: Res<GameSettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut global_volume: ResMut<GlobalVolume>,
+) {
+    if let Ok(mut window) = window_query.get_single_mut() {
+        window.mode = if game_settings.fullscreen {
+            WindowMode::BorderlessFullscreen
+        } else {
+            WindowMode::Windowed
+        };
+        let (width, height) = game_settings.resolution;
+        window.resolution.set(width, height);
+        window.present_mode = if game_settings.vsync {
+            PresentMode::AutoVsync
+        } else {
+            PresentMode::AutoNoVsync
+        };
+    }
+    ui_scale.scale = game_settings.ui_scale;
+    *global_volume = GlobalVolume::new(game_settings.master_volume);
+}
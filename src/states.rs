@@ -4,5 +4,9 @@ use bevy::prelude::*;
 pub enum AppState {
     #[default]
     MainMenu,
+    CharacterSelect,
     Game,
+    Settings,
+    PowerUps,
+    Records,
 }
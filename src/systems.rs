@@ -1,6 +1,10 @@
 use bevy::{prelude::*, window::PrimaryWindow};
 
-use super::states::AppState;
+use super::{
+    game::{history::events::AbandonRunEvent, states::GameState},
+    input::{actions::Action, resources::InputBindings},
+    states::AppState,
+};
 
 pub fn spawn_camera(mut commands: Commands, window_query: Query<&Window, With<PrimaryWindow>>) {
     let window = window_query.get_single().unwrap();
@@ -13,13 +17,26 @@ pub fn spawn_camera(mut commands: Commands, window_query: Query<&Window, With<Pr
 
 pub fn toggle_app_state(
     keyboard_input: Res<Input<KeyCode>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    input_bindings: Res<InputBindings>,
     current_app_state: Res<State<AppState>>,
     mut next_app_state: ResMut<NextState<AppState>>,
+    current_game_state: Res<State<GameState>>,
+    mut abandon_run_event_writer: EventWriter<AbandonRunEvent>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::M) {
+    if input_bindings.just_pressed_any(Action::ToMenu, &keyboard_input, &gamepad_buttons) {
         match *current_app_state.get() {
-            AppState::MainMenu => next_app_state.set(AppState::Game),
-            AppState::Game => next_app_state.set(AppState::MainMenu),
+            AppState::MainMenu => next_app_state.set(AppState::CharacterSelect),
+            AppState::Game => match *current_game_state.get() {
+                // A run still on its start screen hasn't begun, so there's nothing to record.
+                GameState::NewGame | GameState::Inactive => next_app_state.set(AppState::MainMenu),
+                _ => abandon_run_event_writer.send(AbandonRunEvent),
+            },
+            // The key might be being rebound.
+            AppState::Settings => {}
+            AppState::CharacterSelect | AppState::PowerUps | AppState::Records => {
+                next_app_state.set(AppState::MainMenu)
+            }
         }
     }
 }